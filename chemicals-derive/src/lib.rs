@@ -0,0 +1,381 @@
+//! `#[derive(Entry)]` for the chemicals-lib entity structs.
+//!
+//! Each field is mapped to one column with `#[field(name = "...")]`, and the
+//! derive generates the `<Struct>Fields` enum together with its `FieldName`,
+//! `Display` and `FromStr` impls and the `Entry` impl for the struct, so the
+//! column names are written exactly once.
+//!
+//! Field attributes:
+//!
+//! - `name = "..."`: the column name, used for both `Display` and `FromStr`.
+//! - `parse = "path"`: read the column as a `String` and convert it with
+//!   `path(&str) -> Result<T, String>` instead of `FieldValue::from_value`.
+//! - `primary_key`: the column that identifies the row. Every entity has
+//!   exactly one, and `from_fields` rejects it blank; a fresh key for a new
+//!   row comes from `ChemicalsEntry::new_key`.
+//! - `references = "Entity"`: the column holds the primary key of `Entity`,
+//!   which becomes a foreign key in the generated SQL schema.
+//! - `index`: the column is looked up by often enough to be indexed.
+//!
+//! Struct attributes:
+//!
+//! - `#[entry(validate = "method")]`: call `method(&self) -> Result<(), String>`
+//!   on the entry built by `from_fields` before returning it.
+//!
+//! Alongside `Entry::from_fields` the derive adds an inherent
+//! `try_from_fields` that returns the structured `ChemicalsError`;
+//! `from_fields` is the same call with the error rendered as a `String`.
+//! Both, together with reading a column from spreadsheet text and the table
+//! layout (named after the struct in snake case), are also exposed through
+//! the `ChemicalsEntry` trait for generic code.
+//!
+//! With chemicals-lib's `serde` feature the derive also implements
+//! `Serialize` and `Deserialize`, keyed by the same column names, and an
+//! inherent `json_schema`. Deserializing goes through `try_from_fields`, so
+//! JSON is held to the same parsing and validation as a database row: an
+//! optional field may be left out, but the primary key, like every other
+//! field, must be present and valid. `create_from_json` is the way to read
+//! a new row without one.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, LitStr, Path, Type};
+
+struct FieldSpec {
+    ident: Ident,
+    ty: Type,
+    variant: Ident,
+    name: String,
+    parse: Option<Path>,
+    primary_key: bool,
+    index: bool,
+    references: Option<Path>,
+}
+
+#[proc_macro_derive(Entry, attributes(entry, field))]
+pub fn derive_entry(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn camel_case(ident: &Ident) -> Ident {
+    let camel: String = ident
+        .to_string()
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    format_ident!("{}", camel, span = ident.span())
+}
+
+fn snake_case(ident: &Ident) -> String {
+    let mut snake = String::new();
+    for (i, c) in ident.to_string().chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+fn field_specs(input: &DeriveInput) -> Result<Vec<FieldSpec>, Error> {
+    let fields = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => &fields.named,
+            _ => return Err(Error::new(input.span(), "#[derive(Entry)] needs a struct with named fields")),
+        },
+        _ => return Err(Error::new(input.span(), "#[derive(Entry)] only supports structs")),
+    };
+
+    let mut specs: Vec<FieldSpec> = Vec::new();
+    for field in fields {
+        let ident = field.ident.clone().expect("named field");
+        let mut name = None;
+        let mut parse = None;
+        let mut primary_key = false;
+        let mut index = false;
+        let mut references = None;
+
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("field")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    name = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("parse") {
+                    parse = Some(meta.value()?.parse::<LitStr>()?.parse::<Path>()?);
+                    Ok(())
+                } else if meta.path.is_ident("primary_key") {
+                    primary_key = true;
+                    Ok(())
+                } else if meta.path.is_ident("index") {
+                    index = true;
+                    Ok(())
+                } else if meta.path.is_ident("references") {
+                    references = Some(meta.value()?.parse::<LitStr>()?.parse::<Path>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown field attribute"))
+                }
+            })?;
+        }
+
+        let name = match name {
+            Some(name) => name,
+            None => return Err(Error::new(ident.span(), "missing #[field(name = \"...\")]")),
+        };
+        if specs.iter().any(|spec| spec.name == name) {
+            return Err(Error::new(ident.span(), format!("duplicate field name \"{}\"", name)));
+        }
+
+        specs.push(FieldSpec {
+            variant: camel_case(&ident),
+            ident,
+            ty: field.ty.clone(),
+            name,
+            parse,
+            primary_key,
+            index,
+            references,
+        });
+    }
+
+    if specs.iter().filter(|spec| spec.primary_key).count() != 1 {
+        return Err(Error::new(input.span(), "#[derive(Entry)] needs exactly one #[field(primary_key)]"));
+    }
+    Ok(specs)
+}
+
+fn validate_method(input: &DeriveInput) -> Result<Option<Ident>, Error> {
+    let mut validate = None;
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("entry")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("validate") {
+                validate = Some(meta.value()?.parse::<LitStr>()?.parse::<Ident>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown entry attribute"))
+            }
+        })?;
+    }
+    Ok(validate)
+}
+
+fn read_field(spec: &FieldSpec, index: usize) -> TokenStream2 {
+    let ty = &spec.ty;
+    let name = &spec.name;
+    let value = quote! {
+        values.get(#index).ok_or_else(|| crate::error::ChemicalsError::MissingColumn { field: #name.to_string() })?
+    };
+    let text = quote! {
+        <::std::string::String as crate::field_value::FieldValue>::from_value(#value, #name)?
+    };
+
+    if let Some(ref parse) = spec.parse {
+        quote! {
+            #parse(&#text).map_err(|message| crate::error::ChemicalsError::invalid_value(#name, message))?
+        }
+    } else {
+        quote! {
+            <#ty as crate::field_value::FieldValue>::from_value(#value, #name)?
+        }
+    }
+}
+
+fn column(spec: &FieldSpec) -> TokenStream2 {
+    let ty = &spec.ty;
+    let name = &spec.name;
+    let primary_key = spec.primary_key;
+    let indexed = spec.index;
+    let references = match spec.references {
+        Some(ref entity) => quote! {
+            Some(crate::entry::Reference {
+                table: <#entity as crate::entry::ChemicalsEntry>::TABLE,
+                column: <#entity as crate::entry::ChemicalsEntry>::PRIMARY_KEY
+            })
+        },
+        None => quote! { None },
+    };
+
+    quote! {
+        crate::entry::Column {
+            name: #name,
+            column_type: <#ty as crate::field_value::FieldValue>::COLUMN_TYPE,
+            nullable: <#ty as crate::field_value::FieldValue>::NULLABLE,
+            primary_key: #primary_key,
+            indexed: #indexed,
+            references: #references
+        }
+    }
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let specs = field_specs(input)?;
+    let validate = validate_method(input)?;
+
+    let vis = &input.vis;
+    let name = &input.ident;
+    let fields_name = format_ident!("{}Fields", name);
+    let title = name.to_string();
+    let table = snake_case(name);
+    let primary_key = specs.iter().find(|spec| spec.primary_key).map(|spec| spec.name.as_str());
+    let key_type = specs.iter().find(|spec| spec.primary_key).map(|spec| &spec.ty);
+    let columns: Vec<TokenStream2> = specs.iter().map(column).collect();
+
+    let idents: Vec<&Ident> = specs.iter().map(|spec| &spec.ident).collect();
+    let types: Vec<&Type> = specs.iter().map(|spec| &spec.ty).collect();
+    let variants: Vec<&Ident> = specs.iter().map(|spec| &spec.variant).collect();
+    let names: Vec<&str> = specs.iter().map(|spec| spec.name.as_str()).collect();
+    let reads: Vec<TokenStream2> = specs.iter().enumerate().map(|(index, spec)| read_field(spec, index)).collect();
+
+    let build = match validate {
+        Some(method) => quote! {
+            let entry = #name { #(#idents: #reads),* };
+            entry.#method().map_err(crate::error::ChemicalsError::Validation)?;
+            Ok(entry)
+        },
+        None => quote! {
+            Ok(#name { #(#idents: #reads),* })
+        },
+    };
+
+    Ok(quote! {
+        #[derive(PartialEq, Clone, Copy, Debug)]
+        #vis enum #fields_name {
+            #(#variants),*
+        }
+
+        impl ::database_lib::interface::FieldName for #fields_name {}
+
+        impl ::std::fmt::Display for #fields_name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                match self {
+                    #(#fields_name::#variants => write!(f, #names)),*
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for #fields_name {
+            type Err = ::std::string::String;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    #(#names => Ok(#fields_name::#variants),)*
+                    _ => Err("Field does not exist".to_string()),
+                }
+            }
+        }
+
+        impl #name {
+            /// Builds the entry from a row in `get_field_names` order,
+            /// reporting a short row, a mistyped column or a failed check
+            /// as a `ChemicalsError`.
+            pub fn try_from_fields(values: &[::database_lib::interface::Value]) -> ::std::result::Result<Self, crate::error::ChemicalsError> {
+                #build
+            }
+        }
+
+        impl ::database_lib::interface::Entry for #name {
+
+            type FieldNames = #fields_name;
+
+            fn from_fields(values: &[::database_lib::interface::Value]) -> ::std::result::Result<Self, ::std::string::String> {
+                #name::try_from_fields(values).map_err(::std::string::String::from)
+            }
+
+            fn get_field_names() -> ::std::vec::Vec<Self::FieldNames> {
+                vec![#(#fields_name::#variants),*]
+            }
+
+            fn get_fields(&self) -> ::std::vec::Vec<::database_lib::interface::Value> {
+                vec![#(crate::field_value::FieldValue::to_value(&self.#idents)),*]
+            }
+
+            fn get_field(&self, field_name: #fields_name) -> ::std::option::Option<::database_lib::interface::Value> {
+                match field_name {
+                    #(#fields_name::#variants => Some(crate::field_value::FieldValue::to_value(&self.#idents))),*
+                }
+            }
+        }
+
+        impl crate::entry::ChemicalsEntry for #name {
+            const TABLE: &'static str = #table;
+            const PRIMARY_KEY: &'static str = #primary_key;
+
+            fn columns() -> ::std::vec::Vec<crate::entry::Column> {
+                vec![#(#columns),*]
+            }
+
+            fn try_from_fields(values: &[::database_lib::interface::Value]) -> ::std::result::Result<Self, crate::error::ChemicalsError> {
+                #name::try_from_fields(values)
+            }
+
+            fn value_from_text(field: &#fields_name, text: &str) -> ::database_lib::interface::Value {
+                match field {
+                    #(#fields_name::#variants => <#types as crate::field_value::FieldValue>::value_from_text(text)),*
+                }
+            }
+
+            fn new_key() -> ::database_lib::interface::Value {
+                crate::field_value::FieldValue::to_value(&<#key_type>::generate())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        const _: () = {
+            #[derive(::serde::Serialize)]
+            #[serde(rename = #title)]
+            struct Borrowed<'a> {
+                #(#[serde(rename = #names)] #idents: &'a #types),*
+            }
+
+            #[derive(::serde::Deserialize)]
+            #[serde(rename = #title, deny_unknown_fields)]
+            struct Owned {
+                #(#[serde(rename = #names)] #idents: #types),*
+            }
+
+            impl ::serde::Serialize for #name {
+                fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+                    ::serde::Serialize::serialize(&Borrowed { #(#idents: &self.#idents),* }, serializer)
+                }
+            }
+
+            impl<'de> ::serde::Deserialize<'de> for #name {
+                fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+                    let owned = <Owned as ::serde::Deserialize>::deserialize(deserializer)?;
+                    let entry = #name { #(#idents: owned.#idents),* };
+                    let values = ::database_lib::interface::Entry::get_fields(&entry);
+                    #name::try_from_fields(&values).map_err(<D::Error as ::serde::de::Error>::custom)
+                }
+            }
+
+            impl #name {
+                /// The JSON Schema of this entity as written by `Serialize`.
+                pub fn json_schema() -> ::serde_json::Value {
+                    crate::json::entry_schema(#title, vec![
+                        #((#names, <#types as crate::json::FieldSchema>::field_schema(),
+                            <#types as crate::json::FieldSchema>::REQUIRED)),*
+                    ])
+                }
+            }
+        };
+    })
+}
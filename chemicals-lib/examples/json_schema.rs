@@ -0,0 +1,9 @@
+//! Prints the JSON Schema for every entity. Regenerate the checked-in copy with
+//! `cargo run --example json_schema --features serde > schema/chemicals.schema.json`.
+
+extern crate chemicals_lib;
+extern crate serde_json;
+
+fn main() {
+    println!("{}", serde_json::to_string_pretty(&chemicals_lib::json_schema()).unwrap());
+}
@@ -0,0 +1,48 @@
+use std::fmt::Display;
+use std::fmt;
+use std::str::FromStr;
+
+/// Whether a corrosive is an acid or a base, which decides the cabinet it
+/// is segregated into. The hazard statements only say it is corrosive.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum AcidBase {
+    Acid,
+    Base
+}
+
+impl Display for AcidBase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AcidBase::Acid => write!(f, "Acid"),
+            AcidBase::Base => write!(f, "Base")
+        }
+    }
+}
+
+impl FromStr for AcidBase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "acid" | "acidic" => Ok(AcidBase::Acid),
+            "base" | "basic" | "alkali" | "alkaline" => Ok(AcidBase::Base),
+            _ => Err(format!("Unknown acid or base: {}", s))
+        }
+    }
+}
+
+#[cfg(test)]
+mod acid_base_tests {
+
+    use std::str::FromStr;
+
+    use acid_base::AcidBase;
+
+    #[test]
+    fn test_acidbase_from_str() {
+        assert_eq!(AcidBase::from_str("Acid"), Ok(AcidBase::Acid));
+        assert_eq!(AcidBase::from_str(" alkaline "), Ok(AcidBase::Base));
+        assert_eq!(AcidBase::from_str(&AcidBase::Base.to_string()), Ok(AcidBase::Base));
+        assert!(AcidBase::from_str("Neutral").is_err());
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt::Display;
+use std::fmt;
+use std::str::FromStr;
+
+/// A CAS Registry Number such as `7732-18-5`, stored without its hyphens.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct CasNumber(u64);
+
+impl CasNumber {
+    /// The check digit expected for the digits in front of it: each digit
+    /// weighted by its position counting from the right, summed, modulo 10.
+    fn check_digit(body: u64) -> u64 {
+        let mut remaining = body;
+        let mut position = 1;
+        let mut sum = 0;
+        while remaining > 0 {
+            sum += (remaining % 10) * position;
+            remaining /= 10;
+            position += 1;
+        }
+        sum % 10
+    }
+
+    pub fn check_digit_is_valid(&self) -> bool {
+        CasNumber::check_digit(self.0 / 10) == self.0 % 10
+    }
+}
+
+impl Display for CasNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{:02}-{}", self.0 / 1000, (self.0 / 10) % 100, self.0 % 10)
+    }
+}
+
+impl FromStr for CasNumber {
+    type Err = String;
+
+    /// Accepts the canonical `NNNNNNN-NN-N` form as well as input missing its
+    /// hyphens or padded with leading zeros, then validates the check digit.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let groups: Vec<&str> = trimmed.split('-').collect();
+        let well_formed = match groups.len() {
+            1 => true,
+            3 => groups[1].len() == 2 && groups[2].len() == 1 && !groups[0].is_empty(),
+            _ => false
+        };
+        if !well_formed || !trimmed.chars().all(|c| c.is_ascii_digit() || c == '-') {
+            return Err(format!("Invalid CAS number format: {}", s));
+        }
+
+        let digits = trimmed.replace('-', "");
+        let digits = digits.trim_start_matches('0');
+        if digits.len() < 5 || digits.len() > 10 {
+            return Err(format!("Invalid CAS number length: {}", s));
+        }
+
+        let cas_number = CasNumber(digits.parse::<u64>().map_err(|_| format!("Invalid CAS number: {}", s))?);
+        if cas_number.check_digit_is_valid() {
+            Ok(cas_number)
+        } else {
+            Err(format!("Invalid CAS number check digit: {}", s))
+        }
+    }
+}
+
+#[cfg(test)]
+mod cas_number_tests {
+
+    use std::str::FromStr;
+
+    use cas_number::CasNumber;
+
+    #[test]
+    fn test_casnumber_from_str() {
+        let water = CasNumber::from_str("7732-18-5").unwrap();
+        assert_eq!(water.to_string(), "7732-18-5");
+
+        let ethanol = CasNumber::from_str("64-17-5").unwrap();
+        assert_eq!(ethanol.to_string(), "64-17-5");
+    }
+
+    #[test]
+    fn test_casnumber_normalizes() {
+        assert_eq!(CasNumber::from_str("7732185").unwrap().to_string(), "7732-18-5");
+        assert_eq!(CasNumber::from_str("0007732-18-5").unwrap().to_string(), "7732-18-5");
+        assert_eq!(CasNumber::from_str(" 67-63-0 ").unwrap().to_string(), "67-63-0");
+    }
+
+    #[test]
+    fn test_casnumber_rejects_invalid() {
+        assert!(CasNumber::from_str("7732-18-4").is_err());
+        assert!(CasNumber::from_str("44-32-192").is_err());
+        assert!(CasNumber::from_str("000-00").is_err());
+        assert!(CasNumber::from_str("77-321-85").is_err());
+        assert!(CasNumber::from_str("CAS 64-17-5").is_err());
+        assert!(CasNumber::from_str("").is_err());
+    }
+}
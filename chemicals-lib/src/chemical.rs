@@ -1,187 +1,95 @@
-use std::fmt::Display;
-use std::fmt;
-use std::str::FromStr;
-
-use database_lib::interface::Entry;
-use database_lib::interface::Value;
-use database_lib::interface::FieldName;
-
-#[derive(Debug, Clone)]
+use acid_base::AcidBase;
+use hazard::Hazard;
+use hazard_rating::{HmisRating, NfpaRating};
+use id::ChemicalId;
+use shelf_life::LifeSpan;
+use signal_word::SignalWord;
+use state_of_matter::StateOfMatter;
+
+#[derive(PartialEq, Debug, Clone, Entry)]
 pub struct Chemical {
-    pub chemical_name: String, 
+    #[field(name = "ID", primary_key)]
+    pub id: ChemicalId,
+    #[field(name = "Chemical Name", index)]
+    pub chemical_name: String,
+    #[field(name = "Purpose")]
     pub purpose: String,
-    pub state_of_matter: String, //change type NO PLASMA
+    #[field(name = "State of Matter")]
+    pub state_of_matter: StateOfMatter,
+    #[field(name = "MSDS/SDS Path")]
     pub msds_sds_path: String,
+    #[field(name = "QR Code")]
     pub qr_code: String, //change type
-    pub opened_life_span: String,
-    pub unopened_life_span: String,
+    #[field(name = "Opened Life Span")]
+    pub opened_life_span: Option<LifeSpan>,
+    #[field(name = "Unopened Life Span")]
+    pub unopened_life_span: Option<LifeSpan>,
+    #[field(name = "Controlled Substance")]
     pub controlled_substance: bool,
+    #[field(name = "Restricted Substance")]
     pub restricted_substance: bool,
+    #[field(name = "Petroleum Base")]
     pub petroleum_base: bool,
-    pub signal_word: String, //leave for new type
+    #[field(name = "Signal Word")]
+    pub signal_word: SignalWord,
+    #[field(name = "Flash Point", parse = "parse_temperature")]
+    pub flash_point: Option<f64>, //degrees Celsius
+    #[field(name = "Boiling Point", parse = "parse_temperature")]
+    pub boiling_point: Option<f64>, //degrees Celsius
+    #[field(name = "NFPA 704")]
+    pub nfpa_rating: Option<NfpaRating>,
+    #[field(name = "HMIS")]
+    pub hmis_rating: Option<HmisRating>,
+    #[field(name = "Acid/Base")]
+    pub acid_base: Option<AcidBase> //for corrosives; blank if not known
 }
 
-#[derive(PartialEq, Clone, Copy, Debug)]
-pub enum ChemicalFields {
-    ChemicalName,
-    Purpose,
-    StateOfMatter,
-    MsdsSdsPath,
-    QrCode,
-    OpenedLifeSpan,
-    UnopenedLifeSpan,
-    ControlledSubstance,
-    RestrictedSubstance,
-    PetroleumBase,
-    SignalWord
+/// Converts degrees Fahrenheit to the degrees Celsius temperatures are
+/// stored in.
+pub fn fahrenheit_to_celsius(degrees: f64) -> f64 {
+    (degrees - 32.0) * 5.0 / 9.0
 }
 
-impl FieldName for ChemicalFields {}
-
-impl Display for ChemicalFields {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            ChemicalFields::ChemicalName => write!(f, "Chemical Name"),
-            ChemicalFields::Purpose => write!(f, "Purpose"),
-            ChemicalFields::StateOfMatter => write!(f, "State of Matter"),
-            ChemicalFields::MsdsSdsPath => write!(f, "MSDS/SDS Path"),
-            ChemicalFields::QrCode => write!(f, "QR Code"),
-            ChemicalFields::OpenedLifeSpan => write!(f, "Opened Life Span"),
-            ChemicalFields::UnopenedLifeSpan => write!(f, "Unopened Life Span"),
-            ChemicalFields::ControlledSubstance => write!(f, "Controlled Substance"),
-            ChemicalFields::RestrictedSubstance => write!(f, "Restricted Substance"),
-            ChemicalFields::PetroleumBase => write!(f, "Petroleum Base"),
-            ChemicalFields::SignalWord => write!(f, "Signal Word")
-        }
+/// Reads a temperature in degrees Celsius. A trailing `C` or `°C` is
+/// allowed, and one in `F` or `°F` is converted; blank means not recorded.
+fn parse_temperature(s: &str) -> Result<Option<f64>, String> {
+    let text = s.trim();
+    if text.is_empty() {
+        return Ok(None);
     }
-}
 
-impl FromStr for ChemicalFields {
-    type Err =String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "Chemical Name" => Ok(ChemicalFields::ChemicalName),
-            "Purpose" => Ok(ChemicalFields::Purpose),
-            "State Of Matter" => Ok(ChemicalFields::StateOfMatter),
-            "MSDS/SDS Path" => Ok(ChemicalFields::MsdsSdsPath),
-            "QR Code" => Ok(ChemicalFields::QrCode),
-            "Opened Life Span" => Ok(ChemicalFields::OpenedLifeSpan),
-            "Unopened Life Span" => Ok(ChemicalFields::UnopenedLifeSpan),
-            "Controlled Substance" => Ok(ChemicalFields::ControlledSubstance),
-            "Restricted Substance" => Ok(ChemicalFields::RestrictedSubstance),
-            "Petroleum Base" => Ok(ChemicalFields::PetroleumBase),
-            "Signal Word" => Ok(ChemicalFields::SignalWord),
-            _=> Err("Field does not exist".to_string()),
-        }
+    let upper = text.to_uppercase();
+    let (number, fahrenheit) = if let Some(number) = upper.strip_suffix('F') {
+        (number, true)
+    } else {
+        (upper.strip_suffix('C').unwrap_or(&upper), false)
+    };
+    match number.trim().trim_end_matches('°').trim().parse::<f64>() {
+        Ok(degrees) if degrees.is_finite() && fahrenheit => Ok(Some(fahrenheit_to_celsius(degrees))),
+        Ok(degrees) if degrees.is_finite() => Ok(Some(degrees)),
+        _ => Err(format!("Invalid temperature: {}", s))
     }
 }
 
-impl Entry for Chemical {
-
-    type FieldNames = ChemicalFields;
-
-    fn from_fields(values: &[Value]) -> Result<Self, String> {
-        if let Value::String(ref chemical_name) = values[0] {
-            if let Value::String(ref purpose) = values[1] {
-                if let Value::String(ref state_of_matter) = values[2] {
-                    if let Value::String(ref msds_sds_path) = values[3] {
-                        if let Value::String(ref qr_code) = values[4] {
-                            if let Value::String(ref opened_life_span) = values[5] {
-                                if let Value::String(ref unopened_life_span) = values[6] {
-                                    if let Value::Boolean(ref controlled_substance) = values[7] {
-                                        if let Value::Boolean(ref restricted_substance) = values[8] {
-                                            if let Value::Boolean(ref petroleum_base) = values[9] {
-                                                if let Value::String(ref signal_word) = values[10] {
-                                                    Ok(Chemical {
-                                                        chemical_name: chemical_name.clone(),
-                                                        purpose: purpose.clone(),
-                                                        state_of_matter: state_of_matter.clone(),
-                                                        msds_sds_path: msds_sds_path.clone(),
-                                                        qr_code: qr_code.clone(),
-                                                        opened_life_span: opened_life_span.clone(),
-                                                        unopened_life_span: unopened_life_span.clone(),
-                                                        controlled_substance: controlled_substance.clone(),
-                                                        restricted_substance: restricted_substance.clone(),
-                                                        petroleum_base: petroleum_base.clone(),
-                                                        signal_word: signal_word.clone()
-                                                    })
-                                                } else {
-                                                    Err("Incorrect type for signal word. Should be String".to_string())
-                                                }
-                                            } else {
-                                                Err("Incorrect type for petroleum base. Should be boolean".to_string())
-                                            }
-                                        } else {
-                                            Err("Incorrect type for restricted substance. Should be boolean".to_string())
-                                        }
-                                    } else {
-                                        Err("Incorrect type for controlled substance. Should be boolean".to_string())
-                                    }
-                                } else {
-                                    Err("Incorrect type for unopened life span. Should be String".to_string())
-                                }
-                            } else {
-                                Err("Incorrect type for opened life span. Should be String".to_string())
-                            }
-                        } else {
-                            Err("Incorrect type for qr code. Should be String".to_string())
-                        }
-                    } else {
-                        Err("Incorrect type for msds/sds path. Should be String".to_string())
-                    }
-                } else {
-                    Err("Incorrect type for state of matter. Should be String".to_string())
-                }
-            } else {
-                Err("Incorrect type for purpose. Should be String".to_string())
-            }
+impl Chemical {
+    /// The signal word the attached hazards call for: the most severe of them,
+    /// or the stored signal word when no hazards are attached.
+    pub fn effective_signal_word(&self, hazards: &[Hazard]) -> SignalWord {
+        if hazards.is_empty() {
+            self.signal_word
         } else {
-            Err("Incorrect type of chemical name. Should be String".to_string())
+            SignalWord::most_severe(hazards.iter().map(|hazard| hazard.signal_word))
         }
     }
 
-    fn get_field_names() -> Vec<Self::FieldNames> {
-        vec![ChemicalFields::ChemicalName,
-            ChemicalFields::Purpose,
-            ChemicalFields::StateOfMatter,
-            ChemicalFields::MsdsSdsPath,
-            ChemicalFields::QrCode,
-            ChemicalFields::OpenedLifeSpan,
-            ChemicalFields::UnopenedLifeSpan,
-            ChemicalFields::ControlledSubstance,
-            ChemicalFields::RestrictedSubstance,
-            ChemicalFields::PetroleumBase,
-            ChemicalFields::SignalWord]
-    }
-
-    fn get_fields(&self) -> Vec<Value> {
-        vec![Value::String(self.chemical_name.clone()),
-            Value::String(self.purpose.clone()),
-            Value::String(self.state_of_matter.clone()),
-            Value::String(self.msds_sds_path.clone()),
-            Value::String(self.qr_code.clone()),
-            Value::String(self.opened_life_span.clone()),
-            Value::String(self.unopened_life_span.clone()),
-            Value::Boolean(self.controlled_substance.clone()),
-            Value::Boolean(self.restricted_substance.clone()),
-            Value::Boolean(self.petroleum_base.clone()),
-            Value::String(self.signal_word.clone())]
-    }
-
-    fn get_field(&self, field_name: ChemicalFields) -> Option<Value> {
-        match field_name {
-            ChemicalFields::ChemicalName => Some(Value::String(self.chemical_name.clone())),
-            ChemicalFields::Purpose => Some(Value::String(self.purpose.clone())),
-            ChemicalFields::StateOfMatter => Some(Value::String(self.state_of_matter.clone())),
-            ChemicalFields::MsdsSdsPath => Some(Value::String(self.msds_sds_path.clone())),
-            ChemicalFields::QrCode => Some(Value::String(self.qr_code.clone())),
-            ChemicalFields::OpenedLifeSpan => Some(Value::String(self.opened_life_span.clone())),
-            ChemicalFields::UnopenedLifeSpan => Some(Value::String(self.unopened_life_span.clone())),
-            ChemicalFields::ControlledSubstance => Some(Value::Boolean(self.controlled_substance.clone())),
-            ChemicalFields::RestrictedSubstance => Some(Value::Boolean(self.restricted_substance.clone())),
-            ChemicalFields::PetroleumBase => Some(Value::Boolean(self.petroleum_base.clone())),
-            ChemicalFields::SignalWord => Some(Value::String(self.signal_word.clone()))
+    /// Flags a stored signal word that disagrees with the attached hazard statements.
+    pub fn validate_signal_word(&self, hazards: &[Hazard]) -> Result<(), String> {
+        let effective = self.effective_signal_word(hazards);
+        if self.signal_word == effective {
+            Ok(())
+        } else {
+            Err(format!("Signal word for {} is {} but its hazards require {}",
+                self.chemical_name, self.signal_word, effective))
         }
     }
 }
@@ -193,6 +101,13 @@ mod chemical_tests {
 
     use chemical::ChemicalFields;
     use chemical::Chemical;
+    use hazard::Hazard;
+    use hazard_rating::{HmisRating, NfpaRating};
+    use shelf_life::LifeSpan;
+    use signal_word::SignalWord;
+    use state_of_matter::StateOfMatter;
+
+    use id::{ChemicalId, HazardId};
 
     use database_lib::interface::Entry;
     use database_lib::interface::Value;
@@ -205,7 +120,7 @@ mod chemical_tests {
         let purpose_field = ChemicalFields::from_str("Purpose");
         assert_eq!(purpose_field, Ok(ChemicalFields::Purpose));
 
-        let stateofmatter_field = ChemicalFields::from_str("State Of Matter");
+        let stateofmatter_field = ChemicalFields::from_str("State of Matter");
         assert_eq!(stateofmatter_field, Ok(ChemicalFields::StateOfMatter));
 
         let msdssdspath_field = ChemicalFields::from_str("MSDS/SDS Path");
@@ -231,11 +146,27 @@ mod chemical_tests {
 
         let signalword_field = ChemicalFields::from_str("Signal Word");
         assert_eq!(signalword_field, Ok(ChemicalFields::SignalWord));
+
+        let flashpoint_field = ChemicalFields::from_str("Flash Point");
+        assert_eq!(flashpoint_field, Ok(ChemicalFields::FlashPoint));
+
+        let boilingpoint_field = ChemicalFields::from_str("Boiling Point");
+        assert_eq!(boilingpoint_field, Ok(ChemicalFields::BoilingPoint));
+
+        let nfparating_field = ChemicalFields::from_str("NFPA 704");
+        assert_eq!(nfparating_field, Ok(ChemicalFields::NfpaRating));
+
+        let hmisrating_field = ChemicalFields::from_str("HMIS");
+        assert_eq!(hmisrating_field, Ok(ChemicalFields::HmisRating));
+
+        let acidbase_field = ChemicalFields::from_str("Acid/Base");
+        assert_eq!(acidbase_field, Ok(ChemicalFields::AcidBase));
     }
 
     #[test]
     fn test_chemical_from_fields() {
         let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
             Value::String("Isopropyl Alcohol".to_string()),
             Value::String("Cleaning".to_string()),
             Value::String("Liquid".to_string()),
@@ -246,92 +177,192 @@ mod chemical_tests {
             Value::Boolean(false),
             Value::Boolean(false),
             Value::Boolean(false),
-            Value::String("Warning".to_string())
+            Value::String("Warning".to_string()),
+            Value::String("53 °F".to_string()),
+            Value::String("82.5".to_string()),
+            Value::String("1-3-0".to_string()),
+            Value::String("2-3-0".to_string()),
+            Value::String("".to_string())
         ];
 
         let chemical = Chemical::from_fields(&fields).unwrap();
 
         assert_eq!(chemical.chemical_name, "Isopropyl Alcohol".to_string());
         assert_eq!(chemical.purpose, "Cleaning".to_string());
-        assert_eq!(chemical.state_of_matter, "Liquid".to_string());
+        assert_eq!(chemical.state_of_matter, StateOfMatter::Liquid);
         assert_eq!(chemical.msds_sds_path, "Isopropyl Alcohol MSDS".to_string());
         assert_eq!(chemical.qr_code, "124".to_string());
-        assert_eq!(chemical.opened_life_span, "20 years".to_string());
-        assert_eq!(chemical.unopened_life_span, "10 years".to_string());
+        assert_eq!(chemical.opened_life_span, Some(LifeSpan::Years(20)));
+        assert_eq!(chemical.unopened_life_span, Some(LifeSpan::Years(10)));
         assert_eq!(chemical.controlled_substance, false);
         assert_eq!(chemical.restricted_substance, false);
         assert_eq!(chemical.petroleum_base, false);
-        assert_eq!(chemical.signal_word, "Warning".to_string());
+        assert_eq!(chemical.signal_word, SignalWord::Warning);
+        assert!((chemical.flash_point.unwrap() - 11.666_666).abs() < 1e-5);
+        assert_eq!(chemical.boiling_point, Some(82.5));
+        assert_eq!(chemical.nfpa_rating, Some(NfpaRating::from_str("1-3-0").unwrap()));
+        assert_eq!(chemical.hmis_rating, Some(HmisRating::from_str("2-3-0").unwrap()));
+        assert_eq!(chemical.acid_base, None);
+    }
+
+    #[test]
+    fn test_chemical_from_fields_rejects_plasma() {
+        let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("Neon".to_string()),
+            Value::String("Signage".to_string()),
+            Value::String("Plasma".to_string()),
+            Value::String("Neon MSDS".to_string()),
+            Value::String("77".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
+            Value::Boolean(false),
+            Value::Boolean(false),
+            Value::Boolean(false),
+            Value::String("Warning".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string())
+        ];
+
+        assert!(Chemical::from_fields(&fields).is_err());
     }
 
     #[test]
     fn test_chemical_get_field_names() {
         let field_names = Chemical::get_field_names();
 
-        assert_eq!(field_names[0], ChemicalFields::ChemicalName);
-        assert_eq!(field_names[1], ChemicalFields::Purpose);
-        assert_eq!(field_names[2], ChemicalFields::StateOfMatter);
-        assert_eq!(field_names[3], ChemicalFields::MsdsSdsPath);
-        assert_eq!(field_names[4], ChemicalFields::QrCode);
-        assert_eq!(field_names[5], ChemicalFields::OpenedLifeSpan);
-        assert_eq!(field_names[6], ChemicalFields::UnopenedLifeSpan);
-        assert_eq!(field_names[7], ChemicalFields::ControlledSubstance);
-        assert_eq!(field_names[8], ChemicalFields::RestrictedSubstance);
-        assert_eq!(field_names[9], ChemicalFields::PetroleumBase);
-        assert_eq!(field_names[10], ChemicalFields::SignalWord);
-        assert_eq!(field_names.len(), 11);
+        assert_eq!(field_names[1], ChemicalFields::ChemicalName);
+        assert_eq!(field_names[2], ChemicalFields::Purpose);
+        assert_eq!(field_names[3], ChemicalFields::StateOfMatter);
+        assert_eq!(field_names[4], ChemicalFields::MsdsSdsPath);
+        assert_eq!(field_names[5], ChemicalFields::QrCode);
+        assert_eq!(field_names[6], ChemicalFields::OpenedLifeSpan);
+        assert_eq!(field_names[7], ChemicalFields::UnopenedLifeSpan);
+        assert_eq!(field_names[8], ChemicalFields::ControlledSubstance);
+        assert_eq!(field_names[9], ChemicalFields::RestrictedSubstance);
+        assert_eq!(field_names[10], ChemicalFields::PetroleumBase);
+        assert_eq!(field_names[11], ChemicalFields::SignalWord);
+        assert_eq!(field_names[12], ChemicalFields::FlashPoint);
+        assert_eq!(field_names[13], ChemicalFields::BoilingPoint);
+        assert_eq!(field_names[14], ChemicalFields::NfpaRating);
+        assert_eq!(field_names[15], ChemicalFields::HmisRating);
+        assert_eq!(field_names[16], ChemicalFields::AcidBase);
+        assert_eq!(field_names.len(), 17);
     }
 
     #[test]
     fn test_chemical_get_fields() {
         let chemical = Chemical {
+            id: ChemicalId::generate(),
             chemical_name: "Epoxy".to_string(),
             purpose: "Adhesive".to_string(),
-            state_of_matter: "Solid".to_string(),
+            state_of_matter: StateOfMatter::Solid,
             msds_sds_path: "Epoxy MSDS".to_string(),
             qr_code: "91622".to_string(),
-            opened_life_span: "5 years".to_string(),
-            unopened_life_span: "10 years".to_string(),
+            opened_life_span: Some(LifeSpan::Years(5)),
+            unopened_life_span: Some(LifeSpan::Years(10)),
             controlled_substance: false,
             restricted_substance: false,
             petroleum_base: false,
-            signal_word: "Warning".to_string()
+            signal_word: SignalWord::Warning,
+            flash_point: None,
+            boiling_point: None,
+            nfpa_rating: None,
+            hmis_rating: None,
+            acid_base: None
         };
 
         let fields = chemical.get_fields();
 
-        assert_eq!(fields[0], Value::String("Epoxy".to_string()));
-        assert_eq!(fields[1], Value::String("Adhesive".to_string()));
-        assert_eq!(fields[2], Value::String("Solid".to_string()));
-        assert_eq!(fields[3], Value::String("Epoxy MSDS".to_string()));
-        assert_eq!(fields[4], Value::String("91622".to_string()));
-        assert_eq!(fields[5], Value::String("5 years".to_string()));
-        assert_eq!(fields[6], Value::String("10 years".to_string()));
-        assert_eq!(fields[7], Value::Boolean(false));
+        assert_eq!(fields[1], Value::String("Epoxy".to_string()));
+        assert_eq!(fields[2], Value::String("Adhesive".to_string()));
+        assert_eq!(fields[3], Value::String("Solid".to_string()));
+        assert_eq!(fields[4], Value::String("Epoxy MSDS".to_string()));
+        assert_eq!(fields[5], Value::String("91622".to_string()));
+        assert_eq!(fields[6], Value::String("5 years".to_string()));
+        assert_eq!(fields[7], Value::String("10 years".to_string()));
         assert_eq!(fields[8], Value::Boolean(false));
         assert_eq!(fields[9], Value::Boolean(false));
-        assert_eq!(fields[10], Value::String("Warning".to_string()));
-        assert_eq!(fields.len(), 11);
+        assert_eq!(fields[10], Value::Boolean(false));
+        assert_eq!(fields[11], Value::String("Warning".to_string()));
+        assert_eq!(fields[12], Value::String("".to_string()));
+        assert_eq!(fields[13], Value::String("".to_string()));
+        assert_eq!(fields[14], Value::String("".to_string()));
+        assert_eq!(fields[15], Value::String("".to_string()));
+        assert_eq!(fields[16], Value::String("".to_string()));
+        assert_eq!(fields.len(), 17);
     }
 
     #[test]
     fn test_chemical_get_field() {
         let chemical = Chemical {
+            id: ChemicalId::generate(),
             chemical_name: "Cyanoacrylate".to_string(),
             purpose: "Adhesive".to_string(),
-            state_of_matter: "Solid".to_string(),
+            state_of_matter: StateOfMatter::Solid,
             msds_sds_path: "Cyanoacrylate".to_string(),
             qr_code: "5746".to_string(),
-            opened_life_span: "15 years".to_string(),
-            unopened_life_span: "15 days".to_string(),
+            opened_life_span: Some(LifeSpan::Years(15)),
+            unopened_life_span: Some(LifeSpan::Days(15)),
             controlled_substance: true,
             restricted_substance: true,
             petroleum_base: true,
-            signal_word: "Danger".to_string()
+            signal_word: SignalWord::Danger,
+            flash_point: None,
+            boiling_point: None,
+            nfpa_rating: None,
+            hmis_rating: None,
+            acid_base: None
         };
 
         let chemical_name = chemical.get_field(ChemicalFields::ChemicalName);
 
         assert_eq!(chemical_name, Some(Value::String("Cyanoacrylate".to_string())));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_chemical_effective_signal_word() {
+        let chemical = Chemical {
+            id: ChemicalId::generate(),
+            chemical_name: "Acetone".to_string(),
+            purpose: "Solvent".to_string(),
+            state_of_matter: StateOfMatter::Liquid,
+            msds_sds_path: "Acetone MSDS".to_string(),
+            qr_code: "67641".to_string(),
+            opened_life_span: Some(LifeSpan::Years(2)),
+            unopened_life_span: Some(LifeSpan::Years(5)),
+            controlled_substance: false,
+            restricted_substance: false,
+            petroleum_base: false,
+            signal_word: SignalWord::Warning,
+            flash_point: None,
+            boiling_point: None,
+            nfpa_rating: None,
+            hmis_rating: None,
+            acid_base: None
+        };
+
+        let hazards = vec![
+            Hazard {
+                id: HazardId::generate(),
+                code: None,
+                statement: "Highly flammable liquid and vapour".to_string(),
+                signal_word: SignalWord::Danger
+            },
+            Hazard {
+                id: HazardId::generate(),
+                code: None,
+                statement: "Causes serious eye irritation".to_string(),
+                signal_word: SignalWord::Warning
+            }
+        ];
+
+        assert_eq!(chemical.effective_signal_word(&hazards), SignalWord::Danger);
+        assert_eq!(chemical.effective_signal_word(&[]), SignalWord::Warning);
+        assert!(chemical.validate_signal_word(&hazards).is_err());
+        assert!(chemical.validate_signal_word(&hazards[1..]).is_ok());
+    }
+}
@@ -0,0 +1,177 @@
+use std::fmt::Display;
+use std::fmt;
+
+use chemical::Chemical;
+use component::Component;
+use id::{ChemicalComponentId, ChemicalId, ComponentId};
+
+/// Percent by weight of a component in a mixture, as the `min`-`max` range
+/// given on the SDS. An exact percentage has `min == max`.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct ConcentrationRange {
+    pub min: f64,
+    pub max: f64
+}
+
+impl ConcentrationRange {
+    pub fn new(min: f64, max: f64) -> Result<ConcentrationRange, String> {
+        if min.is_finite() && max.is_finite() && min >= 0.0 && min <= max && max <= 100.0 {
+            Ok(ConcentrationRange { min, max })
+        } else {
+            Err(format!("Invalid concentration range: {}-{}%", min, max))
+        }
+    }
+}
+
+impl Display for ConcentrationRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.min == self.max {
+            write!(f, "{}%", self.min)
+        } else {
+            write!(f, "{}-{}%", self.min, self.max)
+        }
+    }
+}
+
+fn parse_concentration(s: &str) -> Result<f64, String> {
+    s.trim().trim_end_matches('%').trim().parse::<f64>().map_err(|_| format!("Invalid concentration: {}", s))
+}
+
+/// Links a chemical to one of the components that make it up.
+#[derive(PartialEq, Debug, Clone, Entry)]
+#[entry(validate = "validate_concentration")]
+pub struct ChemicalComponent {
+    #[field(name = "ID", primary_key)]
+    pub id: ChemicalComponentId,
+    #[field(name = "Chemical ID", references = "Chemical")]
+    pub chemical_id: ChemicalId,
+    #[field(name = "Component ID", references = "Component")]
+    pub component_id: ComponentId,
+    #[field(name = "Minimum Concentration", parse = "parse_concentration")]
+    pub minimum_concentration: f64,
+    #[field(name = "Maximum Concentration", parse = "parse_concentration")]
+    pub maximum_concentration: f64
+}
+
+impl ChemicalComponent {
+    pub fn concentration(&self) -> ConcentrationRange {
+        ConcentrationRange { min: self.minimum_concentration, max: self.maximum_concentration }
+    }
+
+    pub fn validate_concentration(&self) -> Result<(), String> {
+        ConcentrationRange::new(self.minimum_concentration, self.maximum_concentration).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod chemical_component_tests {
+
+    use std::str::FromStr;
+
+    use chemical_component::{ChemicalComponent, ChemicalComponentFields, ConcentrationRange};
+
+    use id::{ChemicalComponentId, ChemicalId, ComponentId};
+
+    use database_lib::interface::Entry;
+    use database_lib::interface::Value;
+
+    #[test]
+    fn test_chemicalcomponentfields_from_str() {
+        let chemicalcomponent_field = ChemicalComponentFields::from_str("Chemical ID");
+        assert_eq!(chemicalcomponent_field, Ok(ChemicalComponentFields::ChemicalId));
+
+        let chemicalcomponent_field = ChemicalComponentFields::from_str("Component ID");
+        assert_eq!(chemicalcomponent_field, Ok(ChemicalComponentFields::ComponentId));
+
+        let chemicalcomponent_field = ChemicalComponentFields::from_str("Minimum Concentration");
+        assert_eq!(chemicalcomponent_field, Ok(ChemicalComponentFields::MinimumConcentration));
+
+        let chemicalcomponent_field = ChemicalComponentFields::from_str("Maximum Concentration");
+        assert_eq!(chemicalcomponent_field, Ok(ChemicalComponentFields::MaximumConcentration));
+    }
+
+    #[test]
+    fn test_concentrationrange_new() {
+        assert!(ConcentrationRange::new(10.0, 30.0).is_ok());
+        assert!(ConcentrationRange::new(30.0, 10.0).is_err());
+        assert!(ConcentrationRange::new(-1.0, 10.0).is_err());
+        assert!(ConcentrationRange::new(50.0, 101.0).is_err());
+        assert_eq!(ConcentrationRange::new(10.0, 30.0).unwrap().to_string(), "10-30%");
+        assert_eq!(ConcentrationRange::new(100.0, 100.0).unwrap().to_string(), "100%");
+    }
+
+    #[test]
+    fn test_chemicalcomponent_from_fields() {
+        let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10".to_string()),
+            Value::String("7c1d9e2f-4a3b-4c5d-8e6f-9a0b1c2d3e4f".to_string()),
+            Value::String("60".to_string()),
+            Value::String("80%".to_string())
+        ];
+
+        let chemical_component = ChemicalComponent::from_fields(&fields).unwrap();
+
+        assert_eq!(chemical_component.chemical_id, ChemicalId::from_str("0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10").unwrap());
+        assert_eq!(chemical_component.component_id, ComponentId::from_str("7c1d9e2f-4a3b-4c5d-8e6f-9a0b1c2d3e4f").unwrap());
+        assert_eq!(chemical_component.concentration(), ConcentrationRange { min: 60.0, max: 80.0 });
+    }
+
+    #[test]
+    fn test_chemicalcomponent_from_fields_rejects_inverted_range() {
+        let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10".to_string()),
+            Value::String("7c1d9e2f-4a3b-4c5d-8e6f-9a0b1c2d3e4f".to_string()),
+            Value::String("80".to_string()),
+            Value::String("60".to_string())
+        ];
+
+        assert!(ChemicalComponent::from_fields(&fields).is_err());
+    }
+
+    #[test]
+    fn test_chemicalcomponent_get_field_names() {
+        let field_names = ChemicalComponent::get_field_names();
+
+        assert_eq!(field_names[1], ChemicalComponentFields::ChemicalId);
+        assert_eq!(field_names[2], ChemicalComponentFields::ComponentId);
+        assert_eq!(field_names[3], ChemicalComponentFields::MinimumConcentration);
+        assert_eq!(field_names[4], ChemicalComponentFields::MaximumConcentration);
+        assert_eq!(field_names.len(), 5);
+    }
+
+    #[test]
+    fn test_chemicalcomponent_get_fields() {
+        let chemical_component = ChemicalComponent {
+            id: ChemicalComponentId::generate(),
+            chemical_id: ChemicalId::generate(),
+            component_id: ComponentId::generate(),
+            minimum_concentration: 70.0,
+            maximum_concentration: 70.0
+        };
+
+        let fields = chemical_component.get_fields();
+
+        assert_eq!(fields[1], Value::String(chemical_component.chemical_id.to_string()));
+        assert_eq!(fields[2], Value::String(chemical_component.component_id.to_string()));
+        assert_eq!(fields[3], Value::String("70".to_string()));
+        assert_eq!(fields[4], Value::String("70".to_string()));
+        assert_eq!(fields.len(), 5);
+    }
+
+    #[test]
+    fn test_chemicalcomponent_get_field() {
+        let chemical_component = ChemicalComponent {
+            id: ChemicalComponentId::generate(),
+            chemical_id: ChemicalId::generate(),
+            component_id: ComponentId::generate(),
+            minimum_concentration: 25.0,
+            maximum_concentration: 35.0
+        };
+
+        let component_id = chemical_component.get_field(ChemicalComponentFields::ComponentId);
+
+        assert_eq!(component_id, Some(Value::String(chemical_component.component_id.to_string())));
+    }
+}
@@ -0,0 +1,88 @@
+use chemical::Chemical;
+use hazard::Hazard;
+use id::{ChemicalHazardId, ChemicalId, HazardId};
+
+/// Links a chemical to one of its hazard statements.
+#[derive(PartialEq, Debug, Clone, Entry)]
+pub struct ChemicalHazard {
+    #[field(name = "ID", primary_key)]
+    pub id: ChemicalHazardId,
+    #[field(name = "Chemical ID", references = "Chemical")]
+    pub chemical_id: ChemicalId,
+    #[field(name = "Hazard ID", references = "Hazard")]
+    pub hazard_id: HazardId
+}
+
+#[cfg(test)]
+mod chemical_hazard_tests {
+
+    use std::str::FromStr;
+
+    use chemical_hazard::{ChemicalHazard, ChemicalHazardFields};
+    use error::ChemicalsError;
+
+    use id::{ChemicalHazardId, ChemicalId, HazardId};
+
+    use database_lib::interface::Entry;
+    use database_lib::interface::Value;
+
+    #[test]
+    fn test_chemicalhazardfields_from_str() {
+        let chemicalhazard_field = ChemicalHazardFields::from_str("Chemical ID");
+        assert_eq!(chemicalhazard_field, Ok(ChemicalHazardFields::ChemicalId));
+
+        let chemicalhazard_field = ChemicalHazardFields::from_str("Hazard ID");
+        assert_eq!(chemicalhazard_field, Ok(ChemicalHazardFields::HazardId));
+    }
+
+    #[test]
+    fn test_chemicalhazard_from_fields() {
+        let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10".to_string()),
+            Value::String("5d2c6a0e-1f7b-4b8e-a3c9-7e4d2b9f0a21".to_string())
+        ];
+
+        let chemical_hazard = ChemicalHazard::from_fields(&fields).unwrap();
+
+        assert_eq!(chemical_hazard.chemical_id, ChemicalId::from_str("0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10").unwrap());
+        assert_eq!(chemical_hazard.hazard_id, HazardId::from_str("5d2c6a0e-1f7b-4b8e-a3c9-7e4d2b9f0a21").unwrap());
+
+        let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("".to_string()),
+            Value::String("5d2c6a0e-1f7b-4b8e-a3c9-7e4d2b9f0a21".to_string())
+        ];
+
+        assert!(ChemicalHazard::from_fields(&fields).is_err());
+
+        let fields = [
+            Value::String(" ".to_string()),
+            Value::String("0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10".to_string()),
+            Value::String("5d2c6a0e-1f7b-4b8e-a3c9-7e4d2b9f0a21".to_string())
+        ];
+
+        match ChemicalHazard::try_from_fields(&fields) {
+            Err(ChemicalsError::InvalidValue { ref field, .. }) => assert_eq!(field, "ID"),
+            other => panic!("expected a blank ID to be rejected, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn test_chemicalhazard_get_fields() {
+        let chemical_hazard = ChemicalHazard {
+            id: ChemicalHazardId::generate(),
+            chemical_id: ChemicalId::generate(),
+            hazard_id: HazardId::generate()
+        };
+
+        let fields = chemical_hazard.get_fields();
+
+        assert_eq!(fields[0], Value::String(chemical_hazard.id.to_string()));
+        assert_eq!(fields[1], Value::String(chemical_hazard.chemical_id.to_string()));
+        assert_eq!(fields[2], Value::String(chemical_hazard.hazard_id.to_string()));
+        assert_eq!(fields.len(), 3);
+        assert_eq!(chemical_hazard.get_field(ChemicalHazardFields::HazardId),
+            Some(Value::String(chemical_hazard.hazard_id.to_string())));
+    }
+}
@@ -1,213 +1,131 @@
-use std::fmt::Display;
-use std::fmt;
-use std::str::FromStr;
+use chrono::NaiveDate;
 
-use database_lib::interface::Entry;
-use database_lib::interface::Value;
-use database_lib::interface::FieldName;
+use chemical::Chemical;
+use container_type::ContainerType;
+use id::{ChemicalId, InventoryId, LocationId};
+use quantity::{Quantity, Unit};
+use storage_location::StorageLocation;
 
-#[derive(Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Entry)]
+#[entry(validate = "validate_chronology")]
 pub struct ChemicalInventory {
+    #[field(name = "ID", primary_key)]
+    pub id: InventoryId,
+    #[field(name = "Chemical ID", references = "Chemical")]
+    pub chemical_id: ChemicalId,
+    #[field(name = "Location ID", references = "StorageLocation")]
+    pub location_id: LocationId,
+    #[field(name = "Lot Number", index)]
     pub lot_number: String,
-    pub purchase_date: String,
-    pub arrival_date: String,
-    pub open_date: String,
-    pub expiration_date: String,
-    pub disposal_date: String, //internal waste drop-off
-    pub removal_date: String, //taken off campus
+    #[field(name = "Purchase Date")]
+    pub purchase_date: Option<NaiveDate>,
+    #[field(name = "Arrival Date")]
+    pub arrival_date: Option<NaiveDate>,
+    #[field(name = "Open Date")]
+    pub open_date: Option<NaiveDate>,
+    #[field(name = "Expiration Date")]
+    pub expiration_date: Option<NaiveDate>,
+    #[field(name = "Disposal Date")]
+    pub disposal_date: Option<NaiveDate>, //internal waste drop-off
+    #[field(name = "Removal Date")]
+    pub removal_date: Option<NaiveDate>, //taken off campus
+    #[field(name = "Disposal Method")]
     pub disposal_method: String,
+    #[field(name = "Active")]
     pub active: bool,
-    pub container_type: String,
-    pub container_size: String,
-    pub unit: String,
-    pub percent_remaining: String
+    #[field(name = "Container Type")]
+    pub container_type: ContainerType,
+    #[field(name = "Container Size", parse = "parse_container_size")]
+    pub container_size: f64,
+    #[field(name = "Unit")]
+    pub unit: Unit,
+    #[field(name = "Percent Remaining", parse = "parse_percent_remaining")]
+    pub percent_remaining: f64
 }
 
-#[derive(PartialEq, Clone, Copy, Debug)]
-pub enum ChemicalInventoryFields {
-    LotNumber,
-    PurchaseDate,
-    ArrivalDate,
-    OpenDate,
-    ExpirationDate,
-    DisposalDate,
-    RemovalDate,
-    DisposalMethod,
-    Active,
-    ContainerType,
-    ContainerSize,
-    Unit,
-    PercentRemaining
+fn parse_container_size(s: &str) -> Result<f64, String> {
+    match s.trim().parse::<f64>() {
+        Ok(size) if size.is_finite() && size >= 0.0 => Ok(size),
+        _ => Err(format!("Invalid container size: {}", s))
+    }
 }
 
-impl FieldName for ChemicalInventoryFields {}
-
-impl Display for ChemicalInventoryFields {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            ChemicalInventoryFields::LotNumber => write!(f, "Lot Number"),
-            ChemicalInventoryFields::PurchaseDate => write!(f, "Purchase Date"),
-            ChemicalInventoryFields::ArrivalDate => write!(f, "Arrival Date"),
-            ChemicalInventoryFields::OpenDate => write!(f, "Open Date"),
-            ChemicalInventoryFields::ExpirationDate => write!(f, "Expiration Date"),
-            ChemicalInventoryFields::DisposalDate => write!(f, "Disposal Date"),
-            ChemicalInventoryFields::RemovalDate => write!(f, "Removal Date"),
-            ChemicalInventoryFields::DisposalMethod => write!(f, "Active"),
-            ChemicalInventoryFields::Active => write!(f, "Active"),
-            ChemicalInventoryFields::ContainerType => write!(f, "Container Type"),
-            ChemicalInventoryFields::ContainerSize => write!(f, "Container Size"),
-            ChemicalInventoryFields::Unit => write!(f, "Unit"),
-            ChemicalInventoryFields::PercentRemaining => write!(f, "Percent Remaining")
-        }
+fn parse_percent_remaining(s: &str) -> Result<f64, String> {
+    match s.trim().trim_end_matches('%').trim().parse::<f64>() {
+        Ok(percent) if (0.0..=100.0).contains(&percent) => Ok(percent),
+        _ => Err(format!("Invalid percent remaining: {}", s))
     }
 }
 
-impl FromStr for ChemicalInventoryFields {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "Lot Number" => Ok(ChemicalInventoryFields::LotNumber),
-            "Purchase Date" => Ok(ChemicalInventoryFields::PurchaseDate),
-            "Arrival Date" => Ok(ChemicalInventoryFields::ArrivalDate),
-            "Open Date" => Ok(ChemicalInventoryFields::OpenDate),
-            "Expiration Date" => Ok(ChemicalInventoryFields::ExpirationDate),
-            "Disposal Date" => Ok(ChemicalInventoryFields::DisposalDate),
-            "Removal Date" => Ok(ChemicalInventoryFields::RemovalDate),
-            "Disposal Method" => Ok(ChemicalInventoryFields::DisposalMethod),
-            "Active" => Ok(ChemicalInventoryFields::Active),
-            "Container Type" => Ok(ChemicalInventoryFields::ContainerType),
-            "Container Size" => Ok(ChemicalInventoryFields::ContainerSize),
-            "Unit" => Ok(ChemicalInventoryFields::Unit),
-            "Percent Remaining" => Ok(ChemicalInventoryFields::PercentRemaining),
-            _=> Err("Field does not exist".to_string()),
-        }
+impl ChemicalInventory {
+    /// The full size of the container.
+    pub fn container_quantity(&self) -> Quantity {
+        Quantity::new(self.container_size, self.unit)
     }
-}
 
-impl Entry for ChemicalInventory {
-
-    type FieldNames = ChemicalInventoryFields;
-
-    fn from_fields(values: &[Value]) -> Result<Self, String> {
-        if let Value::String(ref lot_number) = values[0] {
-            if let Value::String(ref purchase_date) = values[1] {
-                if let Value::String(ref arrival_date) = values[2] {
-                    if let Value::String(ref open_date) = values[3] {
-                        if let Value::String(ref expiration_date) = values[4] {
-                            if let Value::String(ref disposal_date) = values[5] {
-                                if let Value::String(ref removal_date) = values[6] {
-                                    if let Value::String(ref disposal_method) = values[7] {
-                                        if let Value::Boolean(ref active) = values[8] {
-                                            if let Value::String(ref container_type) = values[9] {
-                                                if let Value::String(ref container_size) = values[10] {
-                                                    if let Value::String(ref unit) = values[11] {
-                                                        if let Value::String(ref percent_remaining) = values[12] {
-                                                            Ok(ChemicalInventory {
-                                                                lot_number: lot_number.clone(),
-                                                                purchase_date: purchase_date.clone(),
-                                                                arrival_date: arrival_date.clone(),
-                                                                open_date: open_date.clone(),
-                                                                expiration_date: expiration_date.clone(),
-                                                                disposal_date: disposal_date.clone(),
-                                                                removal_date: removal_date.clone(),
-                                                                disposal_method: disposal_method.clone(),
-                                                                active: active.clone(),
-                                                                container_type: container_type.clone(),
-                                                                container_size: container_size.clone(),
-                                                                unit: unit.clone(),
-                                                                percent_remaining: percent_remaining.clone()
-                                                            })
-                                                        } else {
-                                                            Err("Incorrect type for percent remaining. Should be String".to_string())
-                                                        }
-                                                    } else {
-                                                        Err("Incorrect type for unit. Should be String".to_string())
-                                                    }
-                                                } else {
-                                                    Err("Incorrect type for container size. Should be String".to_string())
-                                                }
-                                            } else {
-                                                Err("Incorrect type for container type. Should be String".to_string())
-                                            }
-                                        } else {
-                                            Err("Incorrect type for active. Should be boolean".to_string())
-                                        }
-                                    } else {
-                                        Err("Incorrect type for disposal method. Shoud be String".to_string())
-                                    }
-                                } else {
-                                    Err("Incorrect type for removal date. Should be String".to_string())
-                                }
-                            } else {
-                                Err("Incorrect type for disposal date. Should be String".to_string())
-                            }
-                        } else {
-                            Err("Incorrect type for expiration date. Should be String".to_string())
-                        }
-                    } else {
-                        Err("Incorrect type for open date. Should be String".to_string())
-                    }
-                } else {
-                    Err("Incorrect type for arrival date. Should be String".to_string())
+    /// How much is left in the container, from its size and percent remaining.
+    pub fn remaining_quantity(&self) -> Quantity {
+        Quantity::new(self.container_size * self.percent_remaining / 100.0, self.unit)
+    }
+
+    /// Rejects records whose dates are out of order, or that are still active
+    /// after being disposed of or removed. Every pair of dates that is
+    /// recorded is compared, so a missing date in between does not hide a
+    /// purchase after the container was opened. The expiration date only has
+    /// to follow the purchase and arrival; a container may be opened, disposed
+    /// of or removed either side of it.
+    pub fn validate_chronology(&self) -> Result<(), String> {
+        let ordered = [
+            ("purchase date", self.purchase_date, "arrival date", self.arrival_date),
+            ("purchase date", self.purchase_date, "open date", self.open_date),
+            ("purchase date", self.purchase_date, "disposal date", self.disposal_date),
+            ("purchase date", self.purchase_date, "removal date", self.removal_date),
+            ("purchase date", self.purchase_date, "expiration date", self.expiration_date),
+            ("arrival date", self.arrival_date, "open date", self.open_date),
+            ("arrival date", self.arrival_date, "disposal date", self.disposal_date),
+            ("arrival date", self.arrival_date, "removal date", self.removal_date),
+            ("arrival date", self.arrival_date, "expiration date", self.expiration_date),
+            ("open date", self.open_date, "disposal date", self.disposal_date),
+            ("open date", self.open_date, "removal date", self.removal_date)
+        ];
+
+        for &(earlier_name, earlier, later_name, later) in ordered.iter() {
+            if let (Some(earlier), Some(later)) = (earlier, later) {
+                if later < earlier {
+                    return Err(format!("The {} ({}) is before the {} ({})", later_name, later, earlier_name, earlier));
                 }
-            } else {
-                Err("Incorrect type for purchase date. Should be String".to_string())
             }
-        } else {
-            Err("Incorrect type for lot number. Should be String".to_string())
         }
-    }
 
-    fn get_field_names() -> Vec<Self::FieldNames> {
-        vec![ChemicalInventoryFields::LotNumber,
-            ChemicalInventoryFields::PurchaseDate,
-            ChemicalInventoryFields::ArrivalDate,
-            ChemicalInventoryFields::OpenDate,
-            ChemicalInventoryFields::ExpirationDate,
-            ChemicalInventoryFields::DisposalDate,
-            ChemicalInventoryFields::RemovalDate,
-            ChemicalInventoryFields::DisposalMethod,
-            ChemicalInventoryFields::Active,
-            ChemicalInventoryFields::ContainerType,
-            ChemicalInventoryFields::ContainerSize,
-            ChemicalInventoryFields::Unit,
-            ChemicalInventoryFields::PercentRemaining]
+        if self.active && (self.disposal_date.is_some() || self.removal_date.is_some()) {
+            return Err("A disposed of or removed container cannot be active".to_string());
+        }
+
+        Ok(())
     }
 
-    fn get_fields(&self) -> Vec<Value> {
-        vec![Value::String(self.lot_number.clone()),
-            Value::String(self.purchase_date.clone()),
-            Value::String(self.arrival_date.clone()),
-            Value::String(self.open_date.clone()),
-            Value::String(self.expiration_date.clone()),
-            Value::String(self.disposal_date.clone()),
-            Value::String(self.removal_date.clone()),
-            Value::String(self.disposal_method.clone()),
-            Value::Boolean(self.active.clone()),
-            Value::String(self.container_type.clone()),
-            Value::String(self.container_size.clone()),
-            Value::String(self.unit.clone()),
-            Value::String(self.percent_remaining.clone())]
+    /// Rejects a container that cannot hold the chemical's state of matter,
+    /// such as a compressed gas in a bottle.
+    pub fn validate_container(&self, chemical: &Chemical) -> Result<(), String> {
+        if self.container_type.can_hold(chemical.state_of_matter) {
+            Ok(())
+        } else {
+            Err(format!("{} {} cannot be stored in a {} (lot {})", chemical.state_of_matter, chemical.chemical_name,
+                self.container_type, self.lot_number))
+        }
     }
+}
 
-    fn get_field(&self, field_name: ChemicalInventoryFields) -> Option<Value> {
-        match field_name {
-            ChemicalInventoryFields::LotNumber => Some(Value::String(self.lot_number.clone())),
-            ChemicalInventoryFields::PurchaseDate => Some(Value::String(self.purchase_date.clone())),
-            ChemicalInventoryFields::ArrivalDate => Some(Value::String(self.arrival_date.clone())),
-            ChemicalInventoryFields::OpenDate => Some(Value::String(self.open_date.clone())),
-            ChemicalInventoryFields::ExpirationDate => Some(Value::String(self.expiration_date.clone())),
-            ChemicalInventoryFields::DisposalDate => Some(Value::String(self.disposal_date.clone())),
-            ChemicalInventoryFields::RemovalDate => Some(Value::String(self.removal_date.clone())),
-            ChemicalInventoryFields::DisposalMethod => Some(Value::String(self.disposal_method.clone())),
-            ChemicalInventoryFields::Active => Some(Value::Boolean(self.active.clone())),
-            ChemicalInventoryFields::ContainerType => Some(Value::String(self.container_type.clone())),
-            ChemicalInventoryFields::ContainerSize => Some(Value::String(self.container_size.clone())),
-            ChemicalInventoryFields::Unit => Some(Value::String(self.unit.clone())),
-            ChemicalInventoryFields::PercentRemaining => Some(Value::String(self.percent_remaining.clone()))
+/// Checks that every container's chemical exists and that the container can
+/// hold it.
+pub fn validate_inventory(inventory: &[ChemicalInventory], chemicals: &[Chemical]) -> Result<(), String> {
+    for container in inventory {
+        match chemicals.iter().find(|chemical| chemical.id == container.chemical_id) {
+            Some(chemical) => container.validate_container(chemical)?,
+            None => return Err(format!("The chemical of lot {} does not exist", container.lot_number))
         }
     }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -215,14 +133,28 @@ mod chemical_inventory_tests {
 
     use std::str::FromStr;
 
+    use chemical::Chemical;
     use chemical_inventory::ChemicalInventoryFields;
-    use chemical_inventory::ChemicalInventory;
+    use chemical_inventory::{validate_inventory, ChemicalInventory};
+    use container_type::ContainerType;
+
+    use chrono::NaiveDate;
+
+    use quantity::Unit;
+    use shelf_life::LifeSpan;
+    use signal_word::SignalWord;
+    use state_of_matter::StateOfMatter;
+
+    use id::{ChemicalId, InventoryId, LocationId};
 
     use database_lib::interface::Entry;
     use database_lib::interface::Value;
 
     #[test]
     fn test_chemicalinventoryfields_from_str() {
+        let locationid_field = ChemicalInventoryFields::from_str("Location ID");
+        assert_eq!(locationid_field, Ok(ChemicalInventoryFields::LocationId));
+
         let lotnumber_field = ChemicalInventoryFields::from_str("Lot Number");
         assert_eq!(lotnumber_field, Ok(ChemicalInventoryFields::LotNumber));
 
@@ -266,13 +198,16 @@ mod chemical_inventory_tests {
     #[test]
     fn test_chemicalinventory_from_fields() {
         let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10".to_string()),
+            Value::String("3c9d2e7a-1f4b-4d8e-a6c5-7b0e9f2d1a43".to_string()),
             Value::String("12".to_string()),
-            Value::String("12/3/2018".to_string()),
             Value::String("11/2/2018".to_string()),
+            Value::String("12/3/2018".to_string()),
             Value::String("3/4/2019".to_string()),
-            Value::String("3/4/2024".to_string()),
-            Value::String("12/4/2018".to_string()),
-            Value::String("1/4/2019".to_string()),
+            Value::String("2024-03-04".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
             Value::String("With Gloves".to_string()),
             Value::Boolean(true),
             Value::String("Plastic Bottle".to_string()),
@@ -283,93 +218,181 @@ mod chemical_inventory_tests {
 
         let chemical_inventory = ChemicalInventory::from_fields(&fields).unwrap();
 
+        assert_eq!(chemical_inventory.chemical_id, ChemicalId::from_str("0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10").unwrap());
+        assert_eq!(chemical_inventory.location_id, LocationId::from_str("3c9d2e7a-1f4b-4d8e-a6c5-7b0e9f2d1a43").unwrap());
         assert_eq!(chemical_inventory.lot_number, "12".to_string());
-        assert_eq!(chemical_inventory.purchase_date, "12/3/2018".to_string());
-        assert_eq!(chemical_inventory.arrival_date, "11/2/2018".to_string());
-        assert_eq!(chemical_inventory.open_date, "3/4/2019".to_string());
-        assert_eq!(chemical_inventory.expiration_date, "3/4/2024".to_string());
-        assert_eq!(chemical_inventory.disposal_date, "12/4/2018".to_string());
-        assert_eq!(chemical_inventory.removal_date, "1/4/2019".to_string());
+        assert_eq!(chemical_inventory.purchase_date, NaiveDate::from_ymd_opt(2018, 11, 2));
+        assert_eq!(chemical_inventory.arrival_date, NaiveDate::from_ymd_opt(2018, 12, 3));
+        assert_eq!(chemical_inventory.open_date, NaiveDate::from_ymd_opt(2019, 3, 4));
+        assert_eq!(chemical_inventory.expiration_date, NaiveDate::from_ymd_opt(2024, 3, 4));
+        assert_eq!(chemical_inventory.disposal_date, None);
+        assert_eq!(chemical_inventory.removal_date, None);
         assert_eq!(chemical_inventory.disposal_method, "With Gloves".to_string());
         assert_eq!(chemical_inventory.active, true);
-        assert_eq!(chemical_inventory.container_type, "Plastic Bottle".to_string());
-        assert_eq!(chemical_inventory.container_size, "3".to_string());
-        assert_eq!(chemical_inventory.unit, "Gallon".to_string());
-        assert_eq!(chemical_inventory.percent_remaining, "50%".to_string());
+        assert_eq!(chemical_inventory.container_type, ContainerType::Other("Plastic Bottle".to_string()));
+        assert_eq!(chemical_inventory.container_size, 3.0);
+        assert_eq!(chemical_inventory.unit, Unit::Gallon);
+        assert_eq!(chemical_inventory.percent_remaining, 50.0);
+    }
+
+    #[test]
+    fn test_chemicalinventory_from_fields_rejects_bad_chronology() {
+        let mut fields = vec![
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10".to_string()),
+            Value::String("3c9d2e7a-1f4b-4d8e-a6c5-7b0e9f2d1a43".to_string()),
+            Value::String("12".to_string()),
+            Value::String("2018-11-02".to_string()),
+            Value::String("2018-12-03".to_string()),
+            Value::String("2018-12-01".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
+            Value::Boolean(true),
+            Value::String("Plastic Bottle".to_string()),
+            Value::String("3".to_string()),
+            Value::String("Gallon".to_string()),
+            Value::String("50%".to_string())
+        ];
+
+        assert!(ChemicalInventory::from_fields(&fields).is_err());
+
+        fields[6] = Value::String("2019-01-15".to_string());
+        fields[8] = Value::String("2019-06-01".to_string());
+        assert!(ChemicalInventory::from_fields(&fields).is_err());
+
+        fields[11] = Value::Boolean(false);
+        assert!(ChemicalInventory::from_fields(&fields).is_ok());
+
+        fields[5] = Value::String("".to_string());
+        fields[4] = Value::String("2019-02-01".to_string());
+        assert!(ChemicalInventory::from_fields(&fields).is_err());
+
+        fields[4] = Value::String("2018-11-02".to_string());
+        fields[7] = Value::String("2018-10-01".to_string());
+        assert!(ChemicalInventory::from_fields(&fields).is_err());
+
+        fields[7] = Value::String("2019-03-01".to_string());
+        assert!(ChemicalInventory::from_fields(&fields).is_ok());
+    }
+
+    #[test]
+    fn test_chemicalinventory_from_fields_rejects_bad_quantities() {
+        let mut fields = vec![
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10".to_string()),
+            Value::String("3c9d2e7a-1f4b-4d8e-a6c5-7b0e9f2d1a43".to_string()),
+            Value::String("12".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
+            Value::Boolean(true),
+            Value::String("Plastic Bottle".to_string()),
+            Value::String("three".to_string()),
+            Value::String("Gallon".to_string()),
+            Value::String("50%".to_string())
+        ];
+
+        assert!(ChemicalInventory::from_fields(&fields).is_err());
+
+        fields[13] = Value::String("3".to_string());
+        fields[14] = Value::String("Bucket".to_string());
+        assert!(ChemicalInventory::from_fields(&fields).is_err());
+
+        fields[14] = Value::String("Gallon".to_string());
+        fields[15] = Value::String("150%".to_string());
+        assert!(ChemicalInventory::from_fields(&fields).is_err());
     }
 
     #[test]
     fn test_chemicalinventory_get_field_names() {
         let field_names = ChemicalInventory::get_field_names();
 
-        assert_eq!(field_names[0], ChemicalInventoryFields::LotNumber);
-        assert_eq!(field_names[1], ChemicalInventoryFields::PurchaseDate);
-        assert_eq!(field_names[2], ChemicalInventoryFields::ArrivalDate);
-        assert_eq!(field_names[3], ChemicalInventoryFields::OpenDate);
-        assert_eq!(field_names[4], ChemicalInventoryFields::ExpirationDate);
-        assert_eq!(field_names[5], ChemicalInventoryFields::DisposalDate);
-        assert_eq!(field_names[6], ChemicalInventoryFields::RemovalDate);
-        assert_eq!(field_names[7], ChemicalInventoryFields::DisposalMethod);
-        assert_eq!(field_names[8], ChemicalInventoryFields::Active);
-        assert_eq!(field_names[9], ChemicalInventoryFields::ContainerType);
-        assert_eq!(field_names[10], ChemicalInventoryFields::ContainerSize);
-        assert_eq!(field_names[11], ChemicalInventoryFields::Unit);
-        assert_eq!(field_names[12], ChemicalInventoryFields::PercentRemaining);
-        assert_eq!(field_names.len(), 13);
+        assert_eq!(field_names[0], ChemicalInventoryFields::Id);
+        assert_eq!(field_names[1], ChemicalInventoryFields::ChemicalId);
+        assert_eq!(field_names[2], ChemicalInventoryFields::LocationId);
+        assert_eq!(field_names[3], ChemicalInventoryFields::LotNumber);
+        assert_eq!(field_names[4], ChemicalInventoryFields::PurchaseDate);
+        assert_eq!(field_names[5], ChemicalInventoryFields::ArrivalDate);
+        assert_eq!(field_names[6], ChemicalInventoryFields::OpenDate);
+        assert_eq!(field_names[7], ChemicalInventoryFields::ExpirationDate);
+        assert_eq!(field_names[8], ChemicalInventoryFields::DisposalDate);
+        assert_eq!(field_names[9], ChemicalInventoryFields::RemovalDate);
+        assert_eq!(field_names[10], ChemicalInventoryFields::DisposalMethod);
+        assert_eq!(field_names[11], ChemicalInventoryFields::Active);
+        assert_eq!(field_names[12], ChemicalInventoryFields::ContainerType);
+        assert_eq!(field_names[13], ChemicalInventoryFields::ContainerSize);
+        assert_eq!(field_names[14], ChemicalInventoryFields::Unit);
+        assert_eq!(field_names[15], ChemicalInventoryFields::PercentRemaining);
+        assert_eq!(field_names.len(), 16);
     }
 
     #[test]
     fn test_chemicalinventory_get_fields() {
         let chemical_inventory = ChemicalInventory {
+            id: InventoryId::generate(),
+            chemical_id: ChemicalId::generate(),
+            location_id: LocationId::generate(),
             lot_number: "1".to_string(),
-            purchase_date: "2".to_string(),
-            arrival_date: "3".to_string(),
-            open_date: "4".to_string(),
-            expiration_date: "5".to_string(),
-            disposal_date: "6".to_string(),
-            removal_date: "7".to_string(),
+            purchase_date: NaiveDate::from_ymd_opt(2018, 1, 2),
+            arrival_date: NaiveDate::from_ymd_opt(2018, 1, 3),
+            open_date: NaiveDate::from_ymd_opt(2018, 1, 4),
+            expiration_date: NaiveDate::from_ymd_opt(2018, 1, 5),
+            disposal_date: NaiveDate::from_ymd_opt(2018, 1, 6),
+            removal_date: None,
             disposal_method: "Trash".to_string(),
             active: false,
-            container_type: "Can".to_string(),
-            container_size: "8".to_string(),
-            unit: "Oz".to_string(),
-            percent_remaining: "20".to_string()
+            container_type: ContainerType::Can,
+            container_size: 8.0,
+            unit: Unit::Ounce,
+            percent_remaining: 20.0
         };
 
         let fields = chemical_inventory.get_fields();
 
-        assert_eq!(fields[0], Value::String("1".to_string()));
-        assert_eq!(fields[1], Value::String("2".to_string()));
-        assert_eq!(fields[2], Value::String("3".to_string()));
-        assert_eq!(fields[3], Value::String("4".to_string()));
-        assert_eq!(fields[4], Value::String("5".to_string()));
-        assert_eq!(fields[5], Value::String("6".to_string()));
-        assert_eq!(fields[6], Value::String("7".to_string()));
-        assert_eq!(fields[7], Value::String("Trash".to_string()));
-        assert_eq!(fields[8], Value::Boolean(false));
-        assert_eq!(fields[9], Value::String("Can".to_string()));
-        assert_eq!(fields[10], Value::String("8".to_string()));
-        assert_eq!(fields[11], Value::String("Oz".to_string()));
-        assert_eq!(fields[12], Value::String("20".to_string()));
-        assert_eq!(fields.len(), 13);
+        assert_eq!(fields[0], Value::String(chemical_inventory.id.to_string()));
+        assert_eq!(fields[1], Value::String(chemical_inventory.chemical_id.to_string()));
+        assert_eq!(fields[2], Value::String(chemical_inventory.location_id.to_string()));
+        assert_eq!(fields[3], Value::String("1".to_string()));
+        assert_eq!(fields[4], Value::String("2018-01-02".to_string()));
+        assert_eq!(fields[5], Value::String("2018-01-03".to_string()));
+        assert_eq!(fields[6], Value::String("2018-01-04".to_string()));
+        assert_eq!(fields[7], Value::String("2018-01-05".to_string()));
+        assert_eq!(fields[8], Value::String("2018-01-06".to_string()));
+        assert_eq!(fields[9], Value::String("".to_string()));
+        assert_eq!(fields[10], Value::String("Trash".to_string()));
+        assert_eq!(fields[11], Value::Boolean(false));
+        assert_eq!(fields[12], Value::String("Can".to_string()));
+        assert_eq!(fields[13], Value::String("8".to_string()));
+        assert_eq!(fields[14], Value::String("oz".to_string()));
+        assert_eq!(fields[15], Value::String("20".to_string()));
+        assert_eq!(fields.len(), 16);
     }
 
     #[test]
     fn test_chemicalinventory_get_field() {
         let chemical_inventory = ChemicalInventory {
+            id: InventoryId::generate(),
+            chemical_id: ChemicalId::generate(),
+            location_id: LocationId::generate(),
             lot_number: "00".to_string(),
-            purchase_date: "00/00/00".to_string(),
-            arrival_date: "01/00/00".to_string(),
-            open_date: "02/00/00".to_string(),
-            expiration_date: "03/00/00".to_string(),
-            disposal_date: "04/00/00".to_string(),
-            removal_date: "05/00/00".to_string(),
+            purchase_date: None,
+            arrival_date: None,
+            open_date: None,
+            expiration_date: None,
+            disposal_date: None,
+            removal_date: None,
             disposal_method: "In Chemical Waste".to_string(),
             active: true,
-            container_type: "Jar".to_string(),
-            container_size: "5".to_string(),
-            unit: "grams".to_string(),
-            percent_remaining: "80".to_string()
+            container_type: ContainerType::Jar,
+            container_size: 5.0,
+            unit: Unit::Gram,
+            percent_remaining: 80.0
         };
 
         let lot_number = chemical_inventory.get_field(ChemicalInventoryFields::LotNumber);
@@ -377,4 +400,58 @@ mod chemical_inventory_tests {
         assert_eq!(lot_number, Some(Value::String("00".to_string())));
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn test_chemicalinventory_validate_container() {
+        let mut chemical = Chemical {
+            id: ChemicalId::generate(),
+            chemical_name: "Nitrogen".to_string(),
+            purpose: "Purge Gas".to_string(),
+            state_of_matter: StateOfMatter::CompressedGas,
+            msds_sds_path: "".to_string(),
+            qr_code: "".to_string(),
+            opened_life_span: Some(LifeSpan::Indefinite),
+            unopened_life_span: Some(LifeSpan::Indefinite),
+            controlled_substance: false,
+            restricted_substance: false,
+            petroleum_base: false,
+            signal_word: SignalWord::Warning,
+            flash_point: None,
+            boiling_point: None,
+            nfpa_rating: None,
+            hmis_rating: None,
+            acid_base: None
+        };
+        let mut container = ChemicalInventory {
+            id: InventoryId::generate(),
+            chemical_id: chemical.id,
+            location_id: LocationId::generate(),
+            lot_number: "N2-1".to_string(),
+            purchase_date: None,
+            arrival_date: None,
+            open_date: None,
+            expiration_date: None,
+            disposal_date: None,
+            removal_date: None,
+            disposal_method: "".to_string(),
+            active: true,
+            container_type: ContainerType::GasCylinder,
+            container_size: 300.0,
+            unit: Unit::Liter,
+            percent_remaining: 100.0
+        };
+
+        assert!(container.validate_container(&chemical).is_ok());
+        assert!(validate_inventory(&[container.clone()], &[chemical.clone()]).is_ok());
+
+        container.container_type = ContainerType::Bottle;
+        assert!(container.validate_container(&chemical).is_err());
+        assert!(validate_inventory(&[container.clone()], &[chemical.clone()]).is_err());
+
+        chemical.state_of_matter = StateOfMatter::CryogenicLiquid;
+        assert!(container.validate_container(&chemical).is_err());
+        container.container_type = ContainerType::Dewar;
+        assert!(container.validate_container(&chemical).is_ok());
+
+        assert!(validate_inventory(&[container], &[]).is_err());
+    }
+}
@@ -0,0 +1,76 @@
+use chemical::Chemical;
+use id::{ChemicalPictogramId, ChemicalId, PictogramId};
+use pictogram::Pictogram;
+
+/// Links a chemical to a pictogram shown on its label.
+#[derive(PartialEq, Debug, Clone, Entry)]
+pub struct ChemicalPictogram {
+    #[field(name = "ID", primary_key)]
+    pub id: ChemicalPictogramId,
+    #[field(name = "Chemical ID", references = "Chemical")]
+    pub chemical_id: ChemicalId,
+    #[field(name = "Pictogram ID", references = "Pictogram")]
+    pub pictogram_id: PictogramId
+}
+
+#[cfg(test)]
+mod chemical_pictogram_tests {
+
+    use std::str::FromStr;
+
+    use chemical_pictogram::{ChemicalPictogram, ChemicalPictogramFields};
+
+    use id::{ChemicalPictogramId, ChemicalId, PictogramId};
+
+    use database_lib::interface::Entry;
+    use database_lib::interface::Value;
+
+    #[test]
+    fn test_chemicalpictogramfields_from_str() {
+        let chemicalpictogram_field = ChemicalPictogramFields::from_str("Chemical ID");
+        assert_eq!(chemicalpictogram_field, Ok(ChemicalPictogramFields::ChemicalId));
+
+        let chemicalpictogram_field = ChemicalPictogramFields::from_str("Pictogram ID");
+        assert_eq!(chemicalpictogram_field, Ok(ChemicalPictogramFields::PictogramId));
+    }
+
+    #[test]
+    fn test_chemicalpictogram_from_fields() {
+        let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10".to_string()),
+            Value::String("5d2c6a0e-1f7b-4b8e-a3c9-7e4d2b9f0a21".to_string())
+        ];
+
+        let chemical_pictogram = ChemicalPictogram::from_fields(&fields).unwrap();
+
+        assert_eq!(chemical_pictogram.chemical_id, ChemicalId::from_str("0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10").unwrap());
+        assert_eq!(chemical_pictogram.pictogram_id, PictogramId::from_str("5d2c6a0e-1f7b-4b8e-a3c9-7e4d2b9f0a21").unwrap());
+
+        let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("".to_string()),
+            Value::String("5d2c6a0e-1f7b-4b8e-a3c9-7e4d2b9f0a21".to_string())
+        ];
+
+        assert!(ChemicalPictogram::from_fields(&fields).is_err());
+    }
+
+    #[test]
+    fn test_chemicalpictogram_get_fields() {
+        let chemical_pictogram = ChemicalPictogram {
+            id: ChemicalPictogramId::generate(),
+            chemical_id: ChemicalId::generate(),
+            pictogram_id: PictogramId::generate()
+        };
+
+        let fields = chemical_pictogram.get_fields();
+
+        assert_eq!(fields[0], Value::String(chemical_pictogram.id.to_string()));
+        assert_eq!(fields[1], Value::String(chemical_pictogram.chemical_id.to_string()));
+        assert_eq!(fields[2], Value::String(chemical_pictogram.pictogram_id.to_string()));
+        assert_eq!(fields.len(), 3);
+        assert_eq!(chemical_pictogram.get_field(ChemicalPictogramFields::PictogramId),
+            Some(Value::String(chemical_pictogram.pictogram_id.to_string())));
+    }
+}
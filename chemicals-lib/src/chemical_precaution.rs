@@ -0,0 +1,76 @@
+use chemical::Chemical;
+use id::{ChemicalPrecautionId, ChemicalId, PrecautionId};
+use precaution::Precaution;
+
+/// Links a chemical to one of its precautionary statements.
+#[derive(PartialEq, Debug, Clone, Entry)]
+pub struct ChemicalPrecaution {
+    #[field(name = "ID", primary_key)]
+    pub id: ChemicalPrecautionId,
+    #[field(name = "Chemical ID", references = "Chemical")]
+    pub chemical_id: ChemicalId,
+    #[field(name = "Precaution ID", references = "Precaution")]
+    pub precaution_id: PrecautionId
+}
+
+#[cfg(test)]
+mod chemical_precaution_tests {
+
+    use std::str::FromStr;
+
+    use chemical_precaution::{ChemicalPrecaution, ChemicalPrecautionFields};
+
+    use id::{ChemicalPrecautionId, ChemicalId, PrecautionId};
+
+    use database_lib::interface::Entry;
+    use database_lib::interface::Value;
+
+    #[test]
+    fn test_chemicalprecautionfields_from_str() {
+        let chemicalprecaution_field = ChemicalPrecautionFields::from_str("Chemical ID");
+        assert_eq!(chemicalprecaution_field, Ok(ChemicalPrecautionFields::ChemicalId));
+
+        let chemicalprecaution_field = ChemicalPrecautionFields::from_str("Precaution ID");
+        assert_eq!(chemicalprecaution_field, Ok(ChemicalPrecautionFields::PrecautionId));
+    }
+
+    #[test]
+    fn test_chemicalprecaution_from_fields() {
+        let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10".to_string()),
+            Value::String("5d2c6a0e-1f7b-4b8e-a3c9-7e4d2b9f0a21".to_string())
+        ];
+
+        let chemical_precaution = ChemicalPrecaution::from_fields(&fields).unwrap();
+
+        assert_eq!(chemical_precaution.chemical_id, ChemicalId::from_str("0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10").unwrap());
+        assert_eq!(chemical_precaution.precaution_id, PrecautionId::from_str("5d2c6a0e-1f7b-4b8e-a3c9-7e4d2b9f0a21").unwrap());
+
+        let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("".to_string()),
+            Value::String("5d2c6a0e-1f7b-4b8e-a3c9-7e4d2b9f0a21".to_string())
+        ];
+
+        assert!(ChemicalPrecaution::from_fields(&fields).is_err());
+    }
+
+    #[test]
+    fn test_chemicalprecaution_get_fields() {
+        let chemical_precaution = ChemicalPrecaution {
+            id: ChemicalPrecautionId::generate(),
+            chemical_id: ChemicalId::generate(),
+            precaution_id: PrecautionId::generate()
+        };
+
+        let fields = chemical_precaution.get_fields();
+
+        assert_eq!(fields[0], Value::String(chemical_precaution.id.to_string()));
+        assert_eq!(fields[1], Value::String(chemical_precaution.chemical_id.to_string()));
+        assert_eq!(fields[2], Value::String(chemical_precaution.precaution_id.to_string()));
+        assert_eq!(fields.len(), 3);
+        assert_eq!(chemical_precaution.get_field(ChemicalPrecautionFields::PrecautionId),
+            Some(Value::String(chemical_precaution.precaution_id.to_string())));
+    }
+}
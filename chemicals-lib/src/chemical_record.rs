@@ -0,0 +1,339 @@
+use std::fmt::Display;
+
+use chemical::Chemical;
+use chemical_component::{ChemicalComponent, ConcentrationRange};
+use chemical_hazard::ChemicalHazard;
+use chemical_pictogram::ChemicalPictogram;
+use chemical_precaution::ChemicalPrecaution;
+use component::Component;
+use error::ChemicalsError;
+use ghs_pictogram::{required_pictograms, GhsPictogram};
+use hazard::Hazard;
+use hazard_rating::{suggest_hmis_rating, suggest_nfpa_rating, HmisRating, NfpaRating};
+use id::{ChemicalId, ComponentId, HazardId, ManufacturerId, PictogramId, PrecautionId};
+use manufacturer::Manufacturer;
+use manufacturer_chemical::ManufacturerChemical;
+use pictogram::Pictogram;
+use precaution::Precaution;
+use segregation::{storage_groups, StorageGroup};
+use signal_word::SignalWord;
+
+/// The rows of every table a `ChemicalRecord` is assembled from, held in
+/// memory. Lookups scan each table, which suits tests and small exports;
+/// a database-backed `RecordSource` queries only the rows it needs.
+#[derive(Debug, Clone, Default)]
+pub struct ChemicalTables {
+    pub chemicals: Vec<Chemical>,
+    pub components: Vec<Component>,
+    pub hazards: Vec<Hazard>,
+    pub precautions: Vec<Precaution>,
+    pub pictograms: Vec<Pictogram>,
+    pub manufacturers: Vec<Manufacturer>,
+    pub chemical_components: Vec<ChemicalComponent>,
+    pub chemical_hazards: Vec<ChemicalHazard>,
+    pub chemical_precautions: Vec<ChemicalPrecaution>,
+    pub chemical_pictograms: Vec<ChemicalPictogram>,
+    pub manufacturer_chemicals: Vec<ManufacturerChemical>
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordComponent {
+    pub component: Component,
+    pub concentration: ConcentrationRange
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordManufacturer {
+    pub manufacturer: Manufacturer,
+    pub manufacturer_number: String
+}
+
+/// A chemical together with everything linked to it.
+#[derive(Debug, Clone)]
+pub struct ChemicalRecord {
+    pub chemical: Chemical,
+    pub components: Vec<RecordComponent>,
+    pub hazards: Vec<Hazard>,
+    pub precautions: Vec<Precaution>,
+    pub pictograms: Vec<Pictogram>,
+    pub manufacturers: Vec<RecordManufacturer>
+}
+
+/// Where a `ChemicalRecord` is loaded from: rows looked up by primary key
+/// and the link rows of one chemical looked up by its id, so a store only
+/// reads what the record needs.
+pub trait RecordSource {
+    fn chemical(&self, id: ChemicalId) -> Result<Option<Chemical>, ChemicalsError>;
+    fn component(&self, id: ComponentId) -> Result<Option<Component>, ChemicalsError>;
+    fn hazard(&self, id: HazardId) -> Result<Option<Hazard>, ChemicalsError>;
+    fn precaution(&self, id: PrecautionId) -> Result<Option<Precaution>, ChemicalsError>;
+    fn pictogram(&self, id: PictogramId) -> Result<Option<Pictogram>, ChemicalsError>;
+    fn manufacturer(&self, id: ManufacturerId) -> Result<Option<Manufacturer>, ChemicalsError>;
+    fn chemical_components(&self, chemical_id: ChemicalId) -> Result<Vec<ChemicalComponent>, ChemicalsError>;
+    fn chemical_hazards(&self, chemical_id: ChemicalId) -> Result<Vec<ChemicalHazard>, ChemicalsError>;
+    fn chemical_precautions(&self, chemical_id: ChemicalId) -> Result<Vec<ChemicalPrecaution>, ChemicalsError>;
+    fn chemical_pictograms(&self, chemical_id: ChemicalId) -> Result<Vec<ChemicalPictogram>, ChemicalsError>;
+    fn manufacturer_chemicals(&self, chemical_id: ChemicalId) -> Result<Vec<ManufacturerChemical>, ChemicalsError>;
+}
+
+impl RecordSource for ChemicalTables {
+    fn chemical(&self, id: ChemicalId) -> Result<Option<Chemical>, ChemicalsError> {
+        Ok(self.chemicals.iter().find(|chemical| chemical.id == id).cloned())
+    }
+
+    fn component(&self, id: ComponentId) -> Result<Option<Component>, ChemicalsError> {
+        Ok(self.components.iter().find(|component| component.id == id).cloned())
+    }
+
+    fn hazard(&self, id: HazardId) -> Result<Option<Hazard>, ChemicalsError> {
+        Ok(self.hazards.iter().find(|hazard| hazard.id == id).cloned())
+    }
+
+    fn precaution(&self, id: PrecautionId) -> Result<Option<Precaution>, ChemicalsError> {
+        Ok(self.precautions.iter().find(|precaution| precaution.id == id).cloned())
+    }
+
+    fn pictogram(&self, id: PictogramId) -> Result<Option<Pictogram>, ChemicalsError> {
+        Ok(self.pictograms.iter().find(|pictogram| pictogram.id == id).cloned())
+    }
+
+    fn manufacturer(&self, id: ManufacturerId) -> Result<Option<Manufacturer>, ChemicalsError> {
+        Ok(self.manufacturers.iter().find(|manufacturer| manufacturer.id == id).cloned())
+    }
+
+    fn chemical_components(&self, chemical_id: ChemicalId) -> Result<Vec<ChemicalComponent>, ChemicalsError> {
+        Ok(self.chemical_components.iter().filter(|link| link.chemical_id == chemical_id).cloned().collect())
+    }
+
+    fn chemical_hazards(&self, chemical_id: ChemicalId) -> Result<Vec<ChemicalHazard>, ChemicalsError> {
+        Ok(self.chemical_hazards.iter().filter(|link| link.chemical_id == chemical_id).cloned().collect())
+    }
+
+    fn chemical_precautions(&self, chemical_id: ChemicalId) -> Result<Vec<ChemicalPrecaution>, ChemicalsError> {
+        Ok(self.chemical_precautions.iter().filter(|link| link.chemical_id == chemical_id).cloned().collect())
+    }
+
+    fn chemical_pictograms(&self, chemical_id: ChemicalId) -> Result<Vec<ChemicalPictogram>, ChemicalsError> {
+        Ok(self.chemical_pictograms.iter().filter(|link| link.chemical_id == chemical_id).cloned().collect())
+    }
+
+    fn manufacturer_chemicals(&self, chemical_id: ChemicalId) -> Result<Vec<ManufacturerChemical>, ChemicalsError> {
+        Ok(self.manufacturer_chemicals.iter().filter(|link| link.chemical_id == chemical_id).cloned().collect())
+    }
+}
+
+/// The row a link points to, or `NotFound` naming it.
+fn linked<T, I: Display>(row: Option<T>, kind: &str, id: I, chemical: &Chemical) -> Result<T, ChemicalsError> {
+    row.ok_or_else(|| ChemicalsError::NotFound(format!("{} {} of {} does not exist", kind, id, chemical.chemical_name)))
+}
+
+impl ChemicalRecord {
+    /// Loads the chemical with `chemical_id` from `source` and follows each
+    /// of its links. A link to a row that does not exist is a `NotFound`
+    /// error.
+    pub fn load<S: RecordSource + ?Sized>(chemical_id: ChemicalId, source: &S) -> Result<ChemicalRecord, ChemicalsError> {
+        let chemical = match source.chemical(chemical_id)? {
+            Some(chemical) => chemical,
+            None => return Err(ChemicalsError::NotFound(format!("Chemical {} does not exist", chemical_id)))
+        };
+
+        let mut components = Vec::new();
+        for link in source.chemical_components(chemical_id)? {
+            components.push(RecordComponent {
+                component: linked(source.component(link.component_id)?, "Component", link.component_id, &chemical)?,
+                concentration: link.concentration()
+            });
+        }
+
+        let mut hazards = Vec::new();
+        for link in source.chemical_hazards(chemical_id)? {
+            hazards.push(linked(source.hazard(link.hazard_id)?, "Hazard", link.hazard_id, &chemical)?);
+        }
+
+        let mut precautions = Vec::new();
+        for link in source.chemical_precautions(chemical_id)? {
+            precautions.push(linked(source.precaution(link.precaution_id)?, "Precaution", link.precaution_id, &chemical)?);
+        }
+
+        let mut pictograms = Vec::new();
+        for link in source.chemical_pictograms(chemical_id)? {
+            pictograms.push(linked(source.pictogram(link.pictogram_id)?, "Pictogram", link.pictogram_id, &chemical)?);
+        }
+
+        let mut manufacturers = Vec::new();
+        for link in source.manufacturer_chemicals(chemical_id)? {
+            manufacturers.push(RecordManufacturer {
+                manufacturer: linked(source.manufacturer(link.manufacturer_id)?, "Manufacturer", link.manufacturer_id,
+                    &chemical)?,
+                manufacturer_number: link.manufacturer_number
+            });
+        }
+
+        Ok(ChemicalRecord {
+            chemical,
+            components,
+            hazards,
+            precautions,
+            pictograms,
+            manufacturers
+        })
+    }
+
+    /// The signal word for the label, derived from the linked hazards.
+    pub fn signal_word(&self) -> SignalWord {
+        self.chemical.effective_signal_word(&self.hazards)
+    }
+
+    /// The pictograms the linked hazards call for, which may differ from
+    /// the pictograms linked directly.
+    pub fn required_pictograms(&self) -> Vec<GhsPictogram> {
+        required_pictograms(&self.hazards, &[])
+    }
+
+    /// The storage groups the linked hazards put the chemical in, most
+    /// restrictive first.
+    pub fn storage_groups(&self) -> Vec<StorageGroup> {
+        storage_groups(&self.chemical, &self.hazards)
+    }
+
+    /// The recorded NFPA 704 rating, or one suggested from the linked
+    /// hazards if none is recorded.
+    pub fn nfpa_rating(&self) -> NfpaRating {
+        match self.chemical.nfpa_rating {
+            Some(ref rating) => rating.clone(),
+            None => suggest_nfpa_rating(&self.chemical, &self.hazards)
+        }
+    }
+
+    /// The recorded HMIS rating, or one suggested from the linked hazards if
+    /// none is recorded.
+    pub fn hmis_rating(&self) -> HmisRating {
+        self.chemical.hmis_rating.unwrap_or_else(|| suggest_hmis_rating(&self.chemical, &self.hazards))
+    }
+}
+
+#[cfg(test)]
+mod chemical_record_tests {
+
+    use chemical::Chemical;
+    use chemical_component::{ChemicalComponent, ConcentrationRange};
+    use chemical_hazard::ChemicalHazard;
+    use chemical_pictogram::ChemicalPictogram;
+    use chemical_precaution::ChemicalPrecaution;
+    use chemical_record::{ChemicalRecord, ChemicalTables};
+    use component::Component;
+    use ghs_pictogram::GhsPictogram;
+    use id::{ChemicalComponentId, ChemicalHazardId, ChemicalId, ChemicalPictogramId, ChemicalPrecautionId, ComponentId,
+        ManufacturerChemicalId, ManufacturerId};
+    use manufacturer::Manufacturer;
+    use manufacturer_chemical::ManufacturerChemical;
+    use pictogram::Pictogram;
+    use precaution::Precaution;
+    use shelf_life::LifeSpan;
+    use signal_word::SignalWord;
+    use state_of_matter::StateOfMatter;
+    use test_fixtures;
+
+    fn tables() -> (ChemicalId, ChemicalTables) {
+        let chemical_id = ChemicalId::generate();
+        let component_id = ComponentId::generate();
+        let manufacturer_id = ManufacturerId::generate();
+        let hazards = test_fixtures::hazards(&["H225", "H319", "H336"]);
+        let precaution = Precaution::from_code("P210", &[]).unwrap();
+        let pictogram = Pictogram::from(GhsPictogram::Flame);
+
+        let tables = ChemicalTables {
+            chemicals: vec![Chemical {
+                id: chemical_id,
+                chemical_name: "Cleaning Isopropyl Alcohol".to_string(),
+                purpose: "Cleaning".to_string(),
+                state_of_matter: StateOfMatter::Liquid,
+                msds_sds_path: "IPA SDS".to_string(),
+                qr_code: "1001".to_string(),
+                opened_life_span: Some(LifeSpan::Years(1)),
+                unopened_life_span: Some(LifeSpan::Years(3)),
+                controlled_substance: false,
+                restricted_substance: false,
+                petroleum_base: false,
+                signal_word: SignalWord::None,
+                flash_point: None,
+                boiling_point: None,
+                nfpa_rating: None,
+                hmis_rating: None,
+                acid_base: None
+            }],
+            components: vec![Component {
+                id: component_id,
+                chemical_name: "Isopropyl Alcohol".to_string(),
+                common_name: "IPA".to_string(),
+                cas_number: Some("67-63-0".parse().unwrap()),
+                substance_number: "".to_string(),
+                trade_secret_number: "".to_string()
+            }],
+            manufacturers: vec![Manufacturer {
+                id: manufacturer_id,
+                company_name: "Techspray".to_string(),
+                address: "".to_string(),
+                phone_number: "".to_string(),
+                website: "".to_string()
+            }],
+            chemical_components: vec![ChemicalComponent {
+                id: ChemicalComponentId::generate(),
+                chemical_id,
+                component_id,
+                minimum_concentration: 99.0,
+                maximum_concentration: 100.0
+            }],
+            chemical_hazards: hazards.iter().map(|hazard| ChemicalHazard {
+                id: ChemicalHazardId::generate(),
+                chemical_id,
+                hazard_id: hazard.id
+            }).collect(),
+            chemical_precautions: vec![ChemicalPrecaution {
+                id: ChemicalPrecautionId::generate(),
+                chemical_id,
+                precaution_id: precaution.id
+            }],
+            chemical_pictograms: vec![ChemicalPictogram {
+                id: ChemicalPictogramId::generate(),
+                chemical_id,
+                pictogram_id: pictogram.id
+            }],
+            manufacturer_chemicals: vec![ManufacturerChemical {
+                id: ManufacturerChemicalId::generate(),
+                manufacturer_id,
+                chemical_id,
+                manufacturer_number: "1610-1G".to_string()
+            }],
+            hazards,
+            precautions: vec![precaution],
+            pictograms: vec![pictogram]
+        };
+        (chemical_id, tables)
+    }
+
+    #[test]
+    fn test_chemicalrecord_load() {
+        let (chemical_id, tables) = tables();
+        let record = ChemicalRecord::load(chemical_id, &tables).unwrap();
+
+        assert_eq!(record.components.len(), 1);
+        assert_eq!(record.components[0].component.common_name, "IPA".to_string());
+        assert_eq!(record.components[0].concentration, ConcentrationRange { min: 99.0, max: 100.0 });
+        assert_eq!(record.hazards.len(), 3);
+        assert_eq!(record.precautions[0].code, Some("P210".to_string()));
+        assert_eq!(record.pictograms[0].ghs_pictogram(), Ok(GhsPictogram::Flame));
+        assert_eq!(record.manufacturers[0].manufacturer_number, "1610-1G".to_string());
+        assert_eq!(record.signal_word(), SignalWord::Danger);
+        assert_eq!(record.required_pictograms(), vec![GhsPictogram::Flame, GhsPictogram::ExclamationMark]);
+    }
+
+    #[test]
+    fn test_chemicalrecord_load_missing_rows() {
+        let (chemical_id, mut tables) = tables();
+        assert!(ChemicalRecord::load(ChemicalId::generate(), &tables).is_err());
+
+        tables.components.clear();
+        assert!(ChemicalRecord::load(chemical_id, &tables).is_err());
+    }
+}
@@ -0,0 +1,162 @@
+use std::fmt::Display;
+use std::fmt;
+use std::str::FromStr;
+
+use state_of_matter::StateOfMatter;
+
+/// The kind of container a lot is kept in. A description that names none
+/// of the known kinds, such as "Plastic Bottle" in older rows, is kept as
+/// written in `Other`.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub enum ContainerType {
+    Bottle,
+    Jar,
+    Jug,
+    Can,
+    Pail,
+    Drum,
+    Bag,
+    Box,
+    Vial,
+    Ampoule,
+    Tube,
+    AerosolCan,
+    GasCylinder,
+    LectureBottle,
+    Dewar,
+    Other(String)
+}
+
+pub const CONTAINER_TYPES: [ContainerType; 15] = [
+    ContainerType::Bottle,
+    ContainerType::Jar,
+    ContainerType::Jug,
+    ContainerType::Can,
+    ContainerType::Pail,
+    ContainerType::Drum,
+    ContainerType::Bag,
+    ContainerType::Box,
+    ContainerType::Vial,
+    ContainerType::Ampoule,
+    ContainerType::Tube,
+    ContainerType::AerosolCan,
+    ContainerType::GasCylinder,
+    ContainerType::LectureBottle,
+    ContainerType::Dewar
+];
+
+impl ContainerType {
+    /// The states of matter this kind of container is made to hold, or
+    /// `None` for a container described in free text.
+    pub fn states(&self) -> Option<&'static [StateOfMatter]> {
+        let states: &'static [StateOfMatter] = match self {
+            ContainerType::Bottle | ContainerType::Jar | ContainerType::Can | ContainerType::Pail | ContainerType::Drum |
+            ContainerType::Vial | ContainerType::Ampoule | ContainerType::Tube =>
+                &[StateOfMatter::Solid, StateOfMatter::Powder, StateOfMatter::Liquid],
+            ContainerType::Jug => &[StateOfMatter::Liquid],
+            ContainerType::Bag | ContainerType::Box => &[StateOfMatter::Solid, StateOfMatter::Powder],
+            ContainerType::AerosolCan => &[StateOfMatter::Aerosol],
+            ContainerType::GasCylinder | ContainerType::LectureBottle => &[StateOfMatter::Gas, StateOfMatter::CompressedGas],
+            ContainerType::Dewar => &[StateOfMatter::CryogenicLiquid],
+            ContainerType::Other(_) => return None
+        };
+        Some(states)
+    }
+
+    /// Whether a chemical in `state` may be kept in this kind of container.
+    /// A container described in free text cannot be judged and is allowed.
+    pub fn can_hold(&self, state: StateOfMatter) -> bool {
+        self.states().is_none_or(|states| states.contains(&state))
+    }
+}
+
+impl Display for ContainerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ContainerType::Bottle => write!(f, "Bottle"),
+            ContainerType::Jar => write!(f, "Jar"),
+            ContainerType::Jug => write!(f, "Jug"),
+            ContainerType::Can => write!(f, "Can"),
+            ContainerType::Pail => write!(f, "Pail"),
+            ContainerType::Drum => write!(f, "Drum"),
+            ContainerType::Bag => write!(f, "Bag"),
+            ContainerType::Box => write!(f, "Box"),
+            ContainerType::Vial => write!(f, "Vial"),
+            ContainerType::Ampoule => write!(f, "Ampoule"),
+            ContainerType::Tube => write!(f, "Tube"),
+            ContainerType::AerosolCan => write!(f, "Aerosol Can"),
+            ContainerType::GasCylinder => write!(f, "Gas Cylinder"),
+            ContainerType::LectureBottle => write!(f, "Lecture Bottle"),
+            ContainerType::Dewar => write!(f, "Dewar"),
+            ContainerType::Other(description) => write!(f, "{}", description)
+        }
+    }
+}
+
+impl FromStr for ContainerType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized: String = s.chars()
+            .filter(|c| c.is_alphanumeric())
+            .collect::<String>()
+            .to_lowercase();
+
+        match normalized.as_str() {
+            "bottle" => Ok(ContainerType::Bottle),
+            "jar" => Ok(ContainerType::Jar),
+            "jug" => Ok(ContainerType::Jug),
+            "can" => Ok(ContainerType::Can),
+            "pail" | "bucket" => Ok(ContainerType::Pail),
+            "drum" => Ok(ContainerType::Drum),
+            "bag" => Ok(ContainerType::Bag),
+            "box" => Ok(ContainerType::Box),
+            "vial" => Ok(ContainerType::Vial),
+            "ampoule" | "ampule" => Ok(ContainerType::Ampoule),
+            "tube" => Ok(ContainerType::Tube),
+            "aerosolcan" | "aerosol" | "spraycan" => Ok(ContainerType::AerosolCan),
+            "gascylinder" | "cylinder" => Ok(ContainerType::GasCylinder),
+            "lecturebottle" => Ok(ContainerType::LectureBottle),
+            "dewar" => Ok(ContainerType::Dewar),
+            "" => Err(format!("Invalid container type: {}", s)),
+            _ => Ok(ContainerType::Other(s.trim().to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod container_type_tests {
+
+    use std::str::FromStr;
+
+    use container_type::{ContainerType, CONTAINER_TYPES};
+    use state_of_matter::StateOfMatter;
+
+    #[test]
+    fn test_containertype_from_str() {
+        assert_eq!(ContainerType::from_str("Gas Cylinder"), Ok(ContainerType::GasCylinder));
+        assert_eq!(ContainerType::from_str("cylinder"), Ok(ContainerType::GasCylinder));
+        assert_eq!(ContainerType::from_str(" lecture bottle "), Ok(ContainerType::LectureBottle));
+        assert_eq!(ContainerType::from_str(" Plastic Bottle "), Ok(ContainerType::Other("Plastic Bottle".to_string())));
+        assert!(ContainerType::from_str(" ").is_err());
+
+        for container in CONTAINER_TYPES.iter() {
+            assert_eq!(ContainerType::from_str(&container.to_string()), Ok(container.clone()));
+        }
+        assert_eq!(ContainerType::Other("Glass Bottle".to_string()).to_string(), "Glass Bottle");
+    }
+
+    #[test]
+    fn test_containertype_can_hold() {
+        assert!(ContainerType::GasCylinder.can_hold(StateOfMatter::CompressedGas));
+        assert!(!ContainerType::Bottle.can_hold(StateOfMatter::CompressedGas));
+        assert!(ContainerType::Dewar.can_hold(StateOfMatter::CryogenicLiquid));
+        assert!(!ContainerType::Bottle.can_hold(StateOfMatter::CryogenicLiquid));
+        assert!(ContainerType::Bottle.can_hold(StateOfMatter::Liquid));
+        assert!(!ContainerType::Bag.can_hold(StateOfMatter::Liquid));
+        assert!(ContainerType::Jar.can_hold(StateOfMatter::Powder));
+        assert!(ContainerType::AerosolCan.can_hold(StateOfMatter::Aerosol));
+        assert!(!ContainerType::Can.can_hold(StateOfMatter::Aerosol));
+        assert!(ContainerType::Other("Steel Tank".to_string()).can_hold(StateOfMatter::CompressedGas));
+    }
+}
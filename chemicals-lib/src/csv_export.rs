@@ -0,0 +1,177 @@
+use std::fmt::Display;
+use std::io::Write;
+
+use csv::{Terminator, WriterBuilder};
+
+use database_lib::interface::{Entry, Value};
+
+use error::ChemicalsError;
+
+/// UTF-8 byte order mark, which spreadsheet programs need to read a CSV
+/// file as UTF-8 rather than the system code page.
+const BYTE_ORDER_MARK: &[u8] = b"\xEF\xBB\xBF";
+
+/// The text of a cell: strings as they are, so blank dates stay blank, and
+/// booleans as `TRUE`/`FALSE`, which spreadsheets read as booleans.
+fn cell_text(value: &Value) -> String {
+    match *value {
+        Value::String(ref s) => s.clone(),
+        Value::Boolean(b) => if b { "TRUE".to_string() } else { "FALSE".to_string() }
+    }
+}
+
+/// `text` made safe to open in a spreadsheet: a cell starting with `=`,
+/// `+`, `-` or `@` would be run as a formula, so it is prefixed with `'`
+/// to keep it text. Plain numbers such as `-20` are left as they are.
+fn guard_formula(text: String) -> String {
+    let formula = text.starts_with(&['=', '+', '-', '@'][..]);
+    if formula && text.trim().parse::<f64>().is_err() {
+        format!("'{}", text)
+    } else {
+        text
+    }
+}
+
+/// Writes `entries` as CSV: a header of the field names, then one row per
+/// entry in `get_field_names` order. Cells are quoted only when needed and
+/// lines end in CRLF, as RFC 4180 has it. The output reads back with
+/// `import_csv`.
+pub fn export_csv<T, W>(entries: &[T], writer: W) -> Result<(), ChemicalsError>
+    where T: Entry, T::FieldNames: Display, W: Write
+{
+    write_csv(entries, writer, |text| text)
+}
+
+fn write_csv<T, W, F>(entries: &[T], writer: W, cell: F) -> Result<(), ChemicalsError>
+    where T: Entry, T::FieldNames: Display, W: Write, F: Fn(String) -> String
+{
+    let mut writer = WriterBuilder::new().terminator(Terminator::CRLF).from_writer(writer);
+    let csv_error = |error: ::csv::Error| ChemicalsError::Csv(error.to_string());
+
+    let header: Vec<String> = T::get_field_names().iter().map(|field_name| field_name.to_string()).collect();
+    writer.write_record(&header).map_err(csv_error)?;
+
+    for entry in entries {
+        let row: Vec<String> = entry.get_fields().iter()
+            .map(|value| cell(cell_text(value)))
+            .collect();
+        writer.write_record(&row).map_err(csv_error)?;
+    }
+
+    writer.flush().map_err(|error| ChemicalsError::Csv(error.to_string()))
+}
+
+/// `export_csv` for files meant to be opened in Excel: preceded by a byte
+/// order mark, and with any cell a spreadsheet would run as a formula
+/// prefixed with `'`. The prefix is kept if the file is imported again.
+pub fn export_spreadsheet_csv<T, W>(entries: &[T], mut writer: W) -> Result<(), ChemicalsError>
+    where T: Entry, T::FieldNames: Display, W: Write
+{
+    writer.write_all(BYTE_ORDER_MARK).map_err(|error| ChemicalsError::Csv(error.to_string()))?;
+    write_csv(entries, writer, guard_formula)
+}
+
+#[cfg(test)]
+mod csv_export_tests {
+
+    use chrono::NaiveDate;
+
+    use chemical_inventory::ChemicalInventory;
+    use container_type::ContainerType;
+    use csv_export::{export_csv, export_spreadsheet_csv};
+    use csv_import::import_csv;
+    use id::{ChemicalId, InventoryId, LocationId};
+    use quantity::Unit;
+
+    fn inventory() -> Vec<ChemicalInventory> {
+        let chemical_id = "0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10".parse::<ChemicalId>().unwrap();
+        let location_id = "3c9d2e7a-1f4b-4d8e-a6c5-7b0e9f2d1a43".parse::<LocationId>().unwrap();
+        vec![ChemicalInventory {
+            id: "5f8c8f4e-2a44-4c4e-8f0b-3a1e6c9d7b21".parse::<InventoryId>().unwrap(),
+            chemical_id,
+            location_id,
+            lot_number: "12".to_string(),
+            purchase_date: NaiveDate::from_ymd_opt(2018, 12, 1),
+            arrival_date: None,
+            open_date: None,
+            expiration_date: None,
+            disposal_date: None,
+            removal_date: None,
+            disposal_method: "Neutralize, \"then\" drain".to_string(),
+            active: true,
+            container_type: ContainerType::Bottle,
+            container_size: 2.5,
+            unit: Unit::Liter,
+            percent_remaining: 50.0
+        }, ChemicalInventory {
+            id: "a7a4e1c2-6d0e-4b7f-9a3c-5b2d8e1f0c94".parse::<InventoryId>().unwrap(),
+            chemical_id,
+            location_id,
+            lot_number: "13".to_string(),
+            purchase_date: None,
+            arrival_date: None,
+            open_date: None,
+            expiration_date: None,
+            disposal_date: NaiveDate::from_ymd_opt(2019, 1, 5),
+            removal_date: None,
+            disposal_method: "Waste drum\nroom 110".to_string(),
+            active: false,
+            container_type: ContainerType::Can,
+            container_size: 1.0,
+            unit: Unit::Gallon,
+            percent_remaining: 0.0
+        }]
+    }
+
+    #[test]
+    fn test_export_csv() {
+        let mut output = Vec::new();
+        export_csv(&inventory(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.split("\r\n").collect();
+
+        assert_eq!(lines[0], "ID,Chemical ID,Location ID,Lot Number,Purchase Date,Arrival Date,Open Date,Expiration Date,\
+            Disposal Date,Removal Date,Disposal Method,Active,Container Type,Container Size,Unit,Percent Remaining");
+        assert_eq!(lines[1], "5f8c8f4e-2a44-4c4e-8f0b-3a1e6c9d7b21,0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10,\
+            3c9d2e7a-1f4b-4d8e-a6c5-7b0e9f2d1a43,12,2018-12-01,,,,,,\"Neutralize, \"\"then\"\" drain\",TRUE,Bottle,2.5,L,50");
+        assert!(lines[2].contains(",\"Waste drum\nroom 110\",FALSE,Can,"));
+    }
+
+    #[test]
+    fn test_export_round_trips_through_import() {
+        let mut output = Vec::new();
+        export_spreadsheet_csv(&inventory(), &mut output).unwrap();
+        assert!(output.starts_with(b"\xEF\xBB\xBFID,"));
+
+        let import = import_csv::<ChemicalInventory, _>(&output[..]).unwrap();
+        assert!(import.errors.is_empty());
+        assert!(import.ignored_columns.is_empty());
+        assert_eq!(import.entries, inventory());
+    }
+
+    #[test]
+    fn test_spreadsheet_export_guards_formulas() {
+        let mut entries = inventory();
+        entries[0].lot_number = "=HYPERLINK(\"http://example.com\")".to_string();
+        entries[1].lot_number = "@SUM(A1)".to_string();
+        entries[1].disposal_method = "-1+1".to_string();
+        entries[0].disposal_method = "+drain".to_string();
+
+        let mut output = Vec::new();
+        export_spreadsheet_csv(&entries, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains(",\"'=HYPERLINK(\"\"http://example.com\"\")\","));
+        assert!(output.contains(",'@SUM(A1),"));
+        assert!(output.contains(",'-1+1,"));
+        assert!(output.contains(",'+drain,"));
+
+        entries[0].lot_number = "-20".to_string();
+        let mut output = Vec::new();
+        export_spreadsheet_csv(&entries, &mut output).unwrap();
+        assert!(String::from_utf8(output).unwrap().contains(",-20,"));
+
+        let mut output = Vec::new();
+        export_csv(&entries[1..], &mut output).unwrap();
+        assert!(String::from_utf8(output).unwrap().contains(",@SUM(A1),"));
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt::Display;
+use std::fmt;
+use std::io::Read;
+use std::str::FromStr;
+
+use csv::{ReaderBuilder, StringRecord};
+
+use database_lib::interface::Value;
+
+use entry::ChemicalsEntry;
+use error::ChemicalsError;
+
+/// A data row that could not be imported. `line` is the 1-based line in the
+/// file the row starts on.
+#[derive(PartialEq, Clone, Debug)]
+pub struct RowError {
+    pub line: u64,
+    pub error: ChemicalsError
+}
+
+impl Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Line {}: {}", self.line, self.error)
+    }
+}
+
+/// The outcome of an import: every row that parsed, every row that did not,
+/// and the header columns that do not name a field.
+#[derive(Clone, Debug)]
+pub struct CsvImport<T> {
+    pub entries: Vec<T>,
+    pub errors: Vec<RowError>,
+    pub ignored_columns: Vec<String>
+}
+
+/// Reads entities of type `T` from CSV with a header row.
+///
+/// Header cells are matched to fields through `T::FieldNames::from_str`, in
+/// any order. Columns that match no field are ignored. Only optional fields
+/// and the ID may be left out, reading as blank; a blank or missing ID is a
+/// new row and is given a freshly generated key, so a sheet without an ID
+/// column imports as new rows. A row that fails to parse is recorded in
+/// `errors` and the import carries on; only a header that cannot be read,
+/// names a field twice or lacks a required field fails the whole import.
+pub fn import_csv<T, R>(reader: R) -> Result<CsvImport<T>, ChemicalsError>
+    where T: ChemicalsEntry, T::FieldNames: FromStr + PartialEq, R: Read
+{
+    let mut reader = ReaderBuilder::new().flexible(true).from_reader(reader);
+    let header = reader.headers().map_err(|error| ChemicalsError::Csv(error.to_string()))?.clone();
+
+    let field_names = T::get_field_names();
+    let table = T::columns();
+    let mut columns: Vec<Option<usize>> = vec![None; field_names.len()];
+    let mut ignored_columns = Vec::new();
+    for (column, cell) in header.iter().enumerate() {
+        let index = T::FieldNames::from_str(cell.trim()).ok()
+            .and_then(|field_name| field_names.iter().position(|name| *name == field_name));
+        match index {
+            Some(index) if columns[index].is_some() =>
+                return Err(ChemicalsError::Csv(format!("Column {} appears more than once", cell.trim()))),
+            Some(index) => columns[index] = Some(column),
+            None => ignored_columns.push(cell.to_string())
+        }
+    }
+
+    for (column, table_column) in columns.iter().zip(table.iter()) {
+        if column.is_none() && !table_column.nullable && !table_column.primary_key {
+            return Err(ChemicalsError::MissingColumn { field: table_column.name.to_string() });
+        }
+    }
+
+    let mut entries = Vec::new();
+    let mut errors = Vec::new();
+    let mut record = StringRecord::new();
+    loop {
+        let line = reader.position().line();
+        match reader.read_record(&mut record) {
+            Ok(false) => break,
+            Ok(true) => {
+                let line = record.position().map_or(line, |position| position.line());
+                let values: Vec<Value> = field_names.iter().zip(columns.iter()).zip(table.iter())
+                    .map(|((field_name, column), table_column)| {
+                        let text = column.and_then(|column| record.get(column)).unwrap_or("");
+                        if table_column.primary_key && text.trim().is_empty() {
+                            T::new_key()
+                        } else {
+                            T::value_from_text(field_name, text)
+                        }
+                    })
+                    .collect();
+
+                match T::try_from_fields(&values) {
+                    Ok(entry) => entries.push(entry),
+                    Err(error) => errors.push(RowError { line, error })
+                }
+            },
+            Err(error) => errors.push(RowError { line, error: ChemicalsError::Csv(error.to_string()) })
+        }
+    }
+
+    Ok(CsvImport {
+        entries,
+        errors,
+        ignored_columns
+    })
+}
+
+#[cfg(test)]
+mod csv_import_tests {
+
+    use chrono::NaiveDate;
+
+    use chemical::Chemical;
+    use chemical_inventory::ChemicalInventory;
+    use csv_import::import_csv;
+    use error::ChemicalsError;
+    use quantity::Unit;
+    use shelf_life::LifeSpan;
+    use state_of_matter::StateOfMatter;
+
+    #[test]
+    fn test_import_inventory() {
+        let csv = "\
+Lot Number,Chemical ID,Location ID,Notes,Unit,Container Size,Percent Remaining,Active,Container Type,Arrival Date,Open Date,Disposal Method
+12,0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10,3c9d2e7a-1f4b-4d8e-a6c5-7b0e9f2d1a43,\"Shelf 3, left\",gal,3,50%,yes,Plastic Bottle,12/3/2018,,
+13,0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10,3c9d2e7a-1f4b-4d8e-a6c5-7b0e9f2d1a43,,gal,3,50%,maybe,Plastic Bottle,,,
+14,not an id,3c9d2e7a-1f4b-4d8e-a6c5-7b0e9f2d1a43,,gal,3,50%,no,Plastic Bottle,,,
+15,0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10,3c9d2e7a-1f4b-4d8e-a6c5-7b0e9f2d1a43,,L,1,100,no,Glass Bottle,2018-12-03,2018-12-01,
+16,0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10,3c9d2e7a-1f4b-4d8e-a6c5-7b0e9f2d1a43,,L,1,100,n,Glass Bottle,,,
+";
+        let import = import_csv::<ChemicalInventory, _>(csv.as_bytes()).unwrap();
+
+        assert_eq!(import.ignored_columns, vec!["Notes".to_string()]);
+        assert_eq!(import.entries.len(), 2);
+        assert_eq!(import.entries[0].lot_number, "12".to_string());
+        assert_eq!(import.entries[0].arrival_date, NaiveDate::from_ymd_opt(2018, 12, 3));
+        assert_eq!(import.entries[0].unit, Unit::Gallon);
+        assert!(import.entries[0].active);
+        assert_eq!(import.entries[1].lot_number, "16".to_string());
+        assert!(!import.entries[1].active);
+        assert!(import.entries[0].id != import.entries[1].id);
+
+        let lines: Vec<u64> = import.errors.iter().map(|error| error.line).collect();
+        assert_eq!(lines, vec![3, 4, 5]);
+        assert_eq!(import.errors[0].error, ChemicalsError::WrongType {
+            field: "Active".to_string(),
+            expected: "Boolean",
+            actual: "String"
+        });
+        match import.errors[1].error {
+            ChemicalsError::InvalidValue { ref field, .. } => assert_eq!(field, "Chemical ID"),
+            ref other => panic!("expected an invalid chemical id, got {:?}", other)
+        }
+        match import.errors[2].error {
+            ChemicalsError::Validation(_) => {},
+            ref other => panic!("expected a chronology error, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn test_import_blank_boolean() {
+        let csv = "\
+Lot Number,Chemical ID,Location ID,Unit,Container Size,Percent Remaining,Active,Container Type,Disposal Method
+12,0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10,3c9d2e7a-1f4b-4d8e-a6c5-7b0e9f2d1a43,L,1,100,,Bottle,
+";
+        let import = import_csv::<ChemicalInventory, _>(csv.as_bytes()).unwrap();
+
+        assert!(import.entries.is_empty());
+        assert_eq!(import.errors.len(), 1);
+        assert_eq!(import.errors[0].line, 2);
+        assert_eq!(import.errors[0].error, ChemicalsError::WrongType {
+            field: "Active".to_string(),
+            expected: "Boolean",
+            actual: "String"
+        });
+    }
+
+    #[test]
+    fn test_import_chemicals() {
+        let csv = "\
+Chemical Name,Purpose,State of Matter,MSDS/SDS Path,QR Code,Opened Life Span,Unopened Life Span,Controlled Substance,Restricted Substance,Petroleum Base,Signal Word
+Acetone,Cleaning,Liquid,,,1 year,3 years,FALSE,FALSE,FALSE,Danger
+Dry Ice,,Plasma,,,,,FALSE,FALSE,FALSE,
+";
+        let import = import_csv::<Chemical, _>(csv.as_bytes()).unwrap();
+
+        assert_eq!(import.entries.len(), 1);
+        assert_eq!(import.entries[0].chemical_name, "Acetone".to_string());
+        assert_eq!(import.entries[0].state_of_matter, StateOfMatter::Liquid);
+        assert_eq!(import.entries[0].unopened_life_span, Some(LifeSpan::Years(3)));
+        assert_eq!(import.errors.len(), 1);
+        assert_eq!(import.errors[0].to_string(),
+            "Line 3: State of Matter: Plasma is not a supported state of matter".to_string());
+    }
+
+    #[test]
+    fn test_import_rejects_missing_required_columns() {
+        let csv = "\
+Chemical Name,Purpose,State of Matter,MSDS/SDS Path,QR Code,Controlled Substance,Signal Word
+Acetone,Cleaning,Liquid,,,FALSE,Danger
+";
+        assert_eq!(import_csv::<Chemical, _>(csv.as_bytes()).err(),
+            Some(ChemicalsError::MissingColumn { field: "Restricted Substance".to_string() }));
+    }
+
+    #[test]
+    fn test_import_rejects_duplicate_columns() {
+        let csv = "Lot Number,Lot Number\n12,13\n";
+        assert!(import_csv::<ChemicalInventory, _>(csv.as_bytes()).is_err());
+    }
+}
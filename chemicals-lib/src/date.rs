@@ -0,0 +1,31 @@
+use chrono::NaiveDate;
+
+/// Parses an inventory date written either as `YYYY-MM-DD` or as `M/D/YYYY`.
+/// A blank string means the date has not happened yet.
+pub fn parse_date(s: &str) -> Result<Option<NaiveDate>, String> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Ok(None);
+    }
+
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(s, "%m/%d/%Y"))
+        .map(Some)
+        .map_err(|_| format!("Invalid date: {}", s))
+}
+
+#[cfg(test)]
+mod date_tests {
+
+    use chrono::NaiveDate;
+
+    use date::parse_date;
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(parse_date("2018-12-03"), Ok(NaiveDate::from_ymd_opt(2018, 12, 3)));
+        assert_eq!(parse_date("12/3/2018"), Ok(NaiveDate::from_ymd_opt(2018, 12, 3)));
+        assert_eq!(parse_date(""), Ok(None));
+        assert!(parse_date("00/00/00").is_err());
+    }
+}
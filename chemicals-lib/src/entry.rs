@@ -0,0 +1,57 @@
+use database_lib::interface::{Entry, Value};
+
+use error::ChemicalsError;
+
+/// What a column holds, which decides its SQL type. `Boolean` columns hold
+/// `Value::Boolean`; all the others hold `Value::String`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ColumnType {
+    Text,
+    Boolean,
+    Number,
+    Date,
+    Id
+}
+
+/// The primary key column of another entity's table.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Reference {
+    pub table: &'static str,
+    pub column: &'static str
+}
+
+/// One column of an entity's table, in `get_field_names` order.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Column {
+    pub name: &'static str,
+    pub column_type: ColumnType,
+    pub nullable: bool,
+    pub primary_key: bool,
+    pub indexed: bool,
+    pub references: Option<Reference>
+}
+
+/// What `#[derive(Entry)]` adds on top of `Entry` for code that works over
+/// any entity: the structured error from reading a row, how each column is
+/// read from plain text, and the shape of the table behind it.
+pub trait ChemicalsEntry: Entry {
+    /// The table name, the struct name in snake case.
+    const TABLE: &'static str;
+
+    /// The name of the primary key column.
+    const PRIMARY_KEY: &'static str;
+
+    /// Every column of the table.
+    fn columns() -> Vec<Column>;
+
+    /// `Entry::from_fields` without flattening the error to a `String`.
+    fn try_from_fields(values: &[Value]) -> Result<Self, ChemicalsError>;
+
+    /// The `Value` for `field` typed into a spreadsheet cell as `text`.
+    fn value_from_text(field: &Self::FieldNames, text: &str) -> Value;
+
+    /// A freshly generated primary key for a row about to be created.
+    /// `from_fields` never makes one up, so importers that accept rows
+    /// without an id fill it in with this first.
+    fn new_key() -> Value;
+}
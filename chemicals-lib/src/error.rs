@@ -0,0 +1,149 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::fmt;
+
+use database_lib::interface::Value;
+
+/// Why a row could not be turned into an entity.
+#[derive(PartialEq, Clone, Debug)]
+pub enum ChemicalsError {
+    /// The row ended before the column for `field`, or a CSV header left
+    /// out a field that must be given.
+    MissingColumn { field: String },
+    /// The column for `field` held a `Value` of the wrong variant.
+    WrongType { field: String, expected: &'static str, actual: &'static str },
+    /// The column for `field` had the right type but could not be parsed.
+    InvalidValue { field: String, message: String },
+    /// The row parsed but breaks a rule across several fields.
+    Validation(String),
+    /// The CSV text itself could not be read.
+    Csv(String),
+    /// The storage backend failed or refused a change.
+    Storage(String),
+    /// A row that was looked up, or that a link points to, does not exist.
+    NotFound(String)
+}
+
+impl ChemicalsError {
+    pub fn wrong_type(field: &str, expected: &'static str, actual: &Value) -> ChemicalsError {
+        ChemicalsError::WrongType {
+            field: field.to_string(),
+            expected,
+            actual: value_type(actual)
+        }
+    }
+
+    pub fn invalid_value(field: &str, message: String) -> ChemicalsError {
+        ChemicalsError::InvalidValue {
+            field: field.to_string(),
+            message
+        }
+    }
+}
+
+/// The name of the `Value` variant `value` holds.
+pub fn value_type(value: &Value) -> &'static str {
+    match *value {
+        Value::String(_) => "String",
+        Value::Boolean(_) => "Boolean"
+    }
+}
+
+impl Display for ChemicalsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChemicalsError::MissingColumn { field } => write!(f, "Missing column for {}", field),
+            ChemicalsError::WrongType { field, expected, actual } =>
+                write!(f, "Incorrect type for {}. Should be {}, found {}", field, expected, actual),
+            ChemicalsError::InvalidValue { field, message } => write!(f, "{}: {}", field, message),
+            ChemicalsError::Validation(message) => write!(f, "{}", message),
+            ChemicalsError::Csv(message) => write!(f, "Invalid CSV: {}", message),
+            ChemicalsError::Storage(message) => write!(f, "Storage error: {}", message),
+            ChemicalsError::NotFound(message) => write!(f, "{}", message)
+        }
+    }
+}
+
+impl Error for ChemicalsError {}
+
+impl From<ChemicalsError> for String {
+    fn from(error: ChemicalsError) -> String {
+        error.to_string()
+    }
+}
+
+#[cfg(test)]
+mod error_tests {
+
+    use chemical_inventory::ChemicalInventory;
+    use error::ChemicalsError;
+    use manufacturer::Manufacturer;
+
+    use database_lib::interface::Entry;
+    use database_lib::interface::Value;
+
+    #[test]
+    fn test_short_row_is_missing_column() {
+        let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("Science Labs".to_string())
+        ];
+
+        assert_eq!(Manufacturer::try_from_fields(&fields).unwrap_err(),
+            ChemicalsError::MissingColumn { field: "Address".to_string() });
+        assert_eq!(Manufacturer::from_fields(&fields).unwrap_err(), "Missing column for Address".to_string());
+        assert_eq!(Manufacturer::try_from_fields(&[]).unwrap_err(),
+            ChemicalsError::MissingColumn { field: "ID".to_string() });
+    }
+
+    #[test]
+    fn test_wrong_type() {
+        let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("Science Labs".to_string()),
+            Value::Boolean(true),
+            Value::String("123-456-7890".to_string()),
+            Value::String("sciencelab.org".to_string())
+        ];
+
+        assert_eq!(Manufacturer::try_from_fields(&fields).unwrap_err(), ChemicalsError::WrongType {
+            field: "Address".to_string(),
+            expected: "String",
+            actual: "Boolean"
+        });
+    }
+
+    #[test]
+    fn test_invalid_value_and_validation() {
+        let mut fields = vec![
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10".to_string()),
+            Value::String("3c9d2e7a-1f4b-4d8e-a6c5-7b0e9f2d1a43".to_string()),
+            Value::String("12".to_string()),
+            Value::String("not a date".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
+            Value::String("".to_string()),
+            Value::Boolean(true),
+            Value::String("Plastic Bottle".to_string()),
+            Value::String("3".to_string()),
+            Value::String("Gallon".to_string()),
+            Value::String("50%".to_string())
+        ];
+
+        match ChemicalInventory::try_from_fields(&fields) {
+            Err(ChemicalsError::InvalidValue { ref field, .. }) => assert_eq!(field, "Purchase Date"),
+            other => panic!("expected an invalid purchase date, got {:?}", other)
+        }
+
+        fields[4] = Value::String("2019-01-01".to_string());
+        fields[5] = Value::String("2018-12-01".to_string());
+        match ChemicalInventory::try_from_fields(&fields) {
+            Err(ChemicalsError::Validation(_)) => {},
+            other => panic!("expected a chronology error, got {:?}", other)
+        }
+    }
+}
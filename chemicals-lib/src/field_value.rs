@@ -0,0 +1,235 @@
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+
+use database_lib::interface::Value;
+
+use acid_base::AcidBase;
+use cas_number::CasNumber;
+use container_type::ContainerType;
+use date::parse_date;
+use entry::ColumnType;
+use error::ChemicalsError;
+use ghs_pictogram::GhsPictogram;
+use hazard_rating::{HmisRating, NfpaRating};
+use id::{ChemicalComponentId, ChemicalHazardId, ChemicalId, ChemicalPictogramId, ChemicalPrecautionId, ComponentId,
+    HazardId, InventoryId, LocationId, ManufacturerChemicalId, ManufacturerId, PictogramId, PrecautionId};
+use quantity::Unit;
+use shelf_life::LifeSpan;
+use signal_word::SignalWord;
+use state_of_matter::StateOfMatter;
+use storage_location::{CabinetType, LocationKind};
+
+/// Conversion between an entity field and the `Value` stored in its column,
+/// used by the code `#[derive(Entry)]` generates. `field` is the column
+/// name reported in errors.
+pub trait FieldValue: Sized {
+    /// What the column holds.
+    const COLUMN_TYPE: ColumnType;
+
+    /// Whether the column may be left empty.
+    const NULLABLE: bool = false;
+
+    fn to_value(&self) -> Value;
+
+    fn from_value(value: &Value, field: &str) -> Result<Self, ChemicalsError>;
+
+    /// The `Value` for this field typed into a spreadsheet cell as `text`.
+    fn value_from_text(text: &str) -> Value {
+        Value::String(text.to_string())
+    }
+}
+
+impl FieldValue for String {
+    const COLUMN_TYPE: ColumnType = ColumnType::Text;
+
+    fn to_value(&self) -> Value {
+        Value::String(self.clone())
+    }
+
+    fn from_value(value: &Value, field: &str) -> Result<Self, ChemicalsError> {
+        if let Value::String(ref s) = *value {
+            Ok(s.clone())
+        } else {
+            Err(ChemicalsError::wrong_type(field, "String", value))
+        }
+    }
+}
+
+impl FieldValue for bool {
+    const COLUMN_TYPE: ColumnType = ColumnType::Boolean;
+
+    fn to_value(&self) -> Value {
+        Value::Boolean(*self)
+    }
+
+    fn from_value(value: &Value, field: &str) -> Result<Self, ChemicalsError> {
+        if let Value::Boolean(b) = *value {
+            Ok(b)
+        } else {
+            Err(ChemicalsError::wrong_type(field, "Boolean", value))
+        }
+    }
+
+    /// Accepts the usual spreadsheet spellings; anything else, a blank cell
+    /// included, stays text so `from_value` reports it.
+    fn value_from_text(text: &str) -> Value {
+        match text.trim().to_lowercase().as_str() {
+            "true" | "yes" | "y" | "x" | "1" => Value::Boolean(true),
+            "false" | "no" | "n" | "0" => Value::Boolean(false),
+            _ => Value::String(text.to_string())
+        }
+    }
+}
+
+impl FieldValue for f64 {
+    const COLUMN_TYPE: ColumnType = ColumnType::Number;
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+
+    fn from_value(value: &Value, field: &str) -> Result<Self, ChemicalsError> {
+        let s = String::from_value(value, field)?;
+        s.trim().parse::<f64>().map_err(|_| ChemicalsError::invalid_value(field, format!("Invalid number: {}", s)))
+    }
+}
+
+/// Dates are stored as ISO `YYYY-MM-DD` text.
+impl FieldValue for NaiveDate {
+    const COLUMN_TYPE: ColumnType = ColumnType::Date;
+
+    fn to_value(&self) -> Value {
+        Value::String(self.format("%Y-%m-%d").to_string())
+    }
+
+    fn from_value(value: &Value, field: &str) -> Result<Self, ChemicalsError> {
+        match parse_date(&String::from_value(value, field)?) {
+            Ok(Some(date)) => Ok(date),
+            Ok(None) => Err(ChemicalsError::invalid_value(field, "Missing date".to_string())),
+            Err(message) => Err(ChemicalsError::invalid_value(field, message))
+        }
+    }
+}
+
+/// An empty string stands for `None`.
+impl<T: FieldValue> FieldValue for Option<T> {
+    const COLUMN_TYPE: ColumnType = T::COLUMN_TYPE;
+    const NULLABLE: bool = true;
+
+    fn to_value(&self) -> Value {
+        match *self {
+            Some(ref inner) => inner.to_value(),
+            None => Value::String(String::new())
+        }
+    }
+
+    fn from_value(value: &Value, field: &str) -> Result<Self, ChemicalsError> {
+        match *value {
+            Value::String(ref s) if s.trim().is_empty() => Ok(None),
+            _ => T::from_value(value, field).map(Some)
+        }
+    }
+
+    fn value_from_text(text: &str) -> Value {
+        if text.trim().is_empty() {
+            Value::String(String::new())
+        } else {
+            T::value_from_text(text)
+        }
+    }
+}
+
+/// Types stored as their `Display` text and read back with `FromStr`.
+macro_rules! string_field_value {
+    ($column_type:expr; $($ty:ty),*) => {
+        $(
+            impl FieldValue for $ty {
+                const COLUMN_TYPE: ColumnType = $column_type;
+
+                fn to_value(&self) -> Value {
+                    Value::String(self.to_string())
+                }
+
+                fn from_value(value: &Value, field: &str) -> Result<Self, ChemicalsError> {
+                    <$ty>::from_str(&String::from_value(value, field)?)
+                        .map_err(|message| ChemicalsError::invalid_value(field, message))
+                }
+            }
+        )*
+    };
+}
+
+string_field_value!(ColumnType::Text; AcidBase, CabinetType, CasNumber, ContainerType, GhsPictogram, HmisRating, LifeSpan, LocationKind, NfpaRating, SignalWord,
+    StateOfMatter, Unit);
+string_field_value!(ColumnType::Id; ChemicalComponentId, ChemicalHazardId, ChemicalId, ChemicalPictogramId, ChemicalPrecautionId, ComponentId,
+    HazardId, InventoryId, LocationId, ManufacturerChemicalId, ManufacturerId, PictogramId, PrecautionId);
+
+#[cfg(test)]
+mod field_value_tests {
+
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    use chrono::NaiveDate;
+
+    use chemical::Chemical;
+    use chemical_component::ChemicalComponent;
+    use chemical_hazard::ChemicalHazard;
+    use chemical_inventory::ChemicalInventory;
+    use chemical_pictogram::ChemicalPictogram;
+    use chemical_precaution::ChemicalPrecaution;
+    use component::Component;
+    use field_value::FieldValue;
+    use hazard::Hazard;
+    use manufacturer::Manufacturer;
+    use manufacturer_chemical::ManufacturerChemical;
+    use pictogram::Pictogram;
+    use precaution::Precaution;
+    use storage_location::StorageLocation;
+
+    use database_lib::interface::Entry;
+    use database_lib::interface::Value;
+
+    fn assert_names_round_trip<T: Entry>()
+        where T::FieldNames: Display + FromStr<Err=String> + PartialEq {
+        for field_name in T::get_field_names() {
+            assert!(T::FieldNames::from_str(&field_name.to_string()) == Ok(field_name));
+        }
+    }
+
+    #[test]
+    fn test_field_names_round_trip() {
+        assert_names_round_trip::<Chemical>();
+        assert_names_round_trip::<ChemicalComponent>();
+        assert_names_round_trip::<ChemicalHazard>();
+        assert_names_round_trip::<ChemicalInventory>();
+        assert_names_round_trip::<ChemicalPictogram>();
+        assert_names_round_trip::<ChemicalPrecaution>();
+        assert_names_round_trip::<Component>();
+        assert_names_round_trip::<Hazard>();
+        assert_names_round_trip::<Manufacturer>();
+        assert_names_round_trip::<ManufacturerChemical>();
+        assert_names_round_trip::<Pictogram>();
+        assert_names_round_trip::<Precaution>();
+        assert_names_round_trip::<StorageLocation>();
+    }
+
+    #[test]
+    fn test_option_field_value() {
+        let date = NaiveDate::from_ymd_opt(2019, 3, 4);
+        assert_eq!(date.to_value(), Value::String("2019-03-04".to_string()));
+        assert_eq!(Option::<NaiveDate>::from_value(&Value::String("".to_string()), "Open Date"), Ok(None));
+        assert_eq!(Option::<NaiveDate>::from_value(&Value::String("3/4/2019".to_string()), "Open Date"), Ok(date));
+        assert!(Option::<NaiveDate>::from_value(&Value::Boolean(true), "Open Date").is_err());
+    }
+
+    #[test]
+    fn test_value_from_text() {
+        assert_eq!(bool::value_from_text(" Yes "), Value::Boolean(true));
+        assert_eq!(bool::value_from_text(""), Value::String("".to_string()));
+        assert_eq!(bool::value_from_text("maybe"), Value::String("maybe".to_string()));
+        assert_eq!(Option::<bool>::value_from_text(""), Value::String("".to_string()));
+        assert_eq!(String::value_from_text("TRUE"), Value::String("TRUE".to_string()));
+    }
+}
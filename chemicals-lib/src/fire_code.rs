@@ -0,0 +1,380 @@
+use std::fmt::Display;
+use std::fmt;
+
+use chemical::{fahrenheit_to_celsius, Chemical};
+use chemical_inventory::ChemicalInventory;
+use chemical_record::{ChemicalRecord, RecordSource};
+use hazard::Hazard;
+use id::{InventoryId, LocationId};
+use quantity::{Quantity, Unit};
+use state_of_matter::StateOfMatter;
+use storage_location::{enclosing_cabinet, inventory_in, CabinetType, StorageLocation};
+
+/// Fire-code classes of flammable and combustible liquids, by flash point
+/// and boiling point as NFPA 30 and the IFC define them.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub enum FireCodeCategory {
+    FlammableLiquidIA,
+    FlammableLiquidIB,
+    FlammableLiquidIC,
+    CombustibleLiquidII,
+    CombustibleLiquidIIIA,
+    CombustibleLiquidIIIB
+}
+
+/// The flash point in °F below which a liquid is Class IA or IB. The class
+/// limits are whole degrees Fahrenheit, and are converted to the Celsius
+/// temperatures are stored in just as a recorded °F temperature is.
+const CLASS_IB_FLASH_POINT: f64 = 73.0;
+/// The flash point in °F dividing flammable from combustible liquids, and
+/// the boiling point dividing Class IA from IB.
+const CLASS_II_FLASH_POINT: f64 = 100.0;
+const CLASS_IIIA_FLASH_POINT: f64 = 140.0;
+const CLASS_IIIB_FLASH_POINT: f64 = 200.0;
+
+impl FireCodeCategory {
+    /// The category of a liquid chemical from its flash point and boiling
+    /// point. A liquid with a flash point under 73 °F and no boiling point
+    /// recorded is taken to be Class IA, the stricter class. Without a flash
+    /// point, the GHS flammable liquid statements among `hazards` stand in,
+    /// each for the strictest class its category spans. Chemicals that are
+    /// not liquids, and liquids with neither, have none.
+    pub fn classify(chemical: &Chemical, hazards: &[Hazard]) -> Option<FireCodeCategory> {
+        if chemical.state_of_matter.phase() != StateOfMatter::Liquid {
+            return None;
+        }
+
+        let flash_point = match chemical.flash_point {
+            Some(flash_point) => flash_point,
+            None => return hazards.iter().filter_map(FireCodeCategory::from_hazard).min()
+        };
+        let below = |temperature: f64, fahrenheit: f64| temperature < fahrenheit_to_celsius(fahrenheit);
+        let category = if below(flash_point, CLASS_IB_FLASH_POINT) {
+            match chemical.boiling_point {
+                Some(boiling_point) if !below(boiling_point, CLASS_II_FLASH_POINT) => FireCodeCategory::FlammableLiquidIB,
+                _ => FireCodeCategory::FlammableLiquidIA
+            }
+        } else if below(flash_point, CLASS_II_FLASH_POINT) {
+            FireCodeCategory::FlammableLiquidIC
+        } else if below(flash_point, CLASS_IIIA_FLASH_POINT) {
+            FireCodeCategory::CombustibleLiquidII
+        } else if below(flash_point, CLASS_IIIB_FLASH_POINT) {
+            FireCodeCategory::CombustibleLiquidIIIA
+        } else {
+            FireCodeCategory::CombustibleLiquidIIIB
+        };
+        Some(category)
+    }
+
+    /// The class a GHS flammable liquid category implies. Category 1 and 2
+    /// liquids flash under 73 °F and boil below and above 95 °F, so both
+    /// reach Class IA's 100 °F boiling point limit; Category 3 flash between
+    /// 73 °F and 140 °F, and Category 4 between 140 °F and 200 °F.
+    fn from_hazard(hazard: &Hazard) -> Option<FireCodeCategory> {
+        match hazard.catalog_entry()?.code {
+            "H224" => Some(FireCodeCategory::FlammableLiquidIA),
+            "H225" => Some(FireCodeCategory::FlammableLiquidIA),
+            "H226" => Some(FireCodeCategory::FlammableLiquidIC),
+            "H227" => Some(FireCodeCategory::CombustibleLiquidIIIA),
+            _ => None
+        }
+    }
+}
+
+impl Display for FireCodeCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FireCodeCategory::FlammableLiquidIA => write!(f, "Class IA flammable liquid"),
+            FireCodeCategory::FlammableLiquidIB => write!(f, "Class IB flammable liquid"),
+            FireCodeCategory::FlammableLiquidIC => write!(f, "Class IC flammable liquid"),
+            FireCodeCategory::CombustibleLiquidII => write!(f, "Class II combustible liquid"),
+            FireCodeCategory::CombustibleLiquidIIIA => write!(f, "Class IIIA combustible liquid"),
+            FireCodeCategory::CombustibleLiquidIIIB => write!(f, "Class IIIB combustible liquid")
+        }
+    }
+}
+
+/// The most a control area may hold of the categories in `categories`,
+/// taken together.
+#[derive(PartialEq, Clone, Debug)]
+pub struct MaqLimit {
+    pub categories: Vec<FireCodeCategory>,
+    pub quantity: Quantity
+}
+
+/// A set of maximum allowable quantities. In a sprinklered control area
+/// every limit is multiplied by `sprinkler_multiplier`, and what is stored
+/// in flammable storage cabinets counts against a limit divided by
+/// `cabinet_multiplier`; the two increases apply together.
+#[derive(PartialEq, Clone, Debug)]
+pub struct MaqTable {
+    pub limits: Vec<MaqLimit>,
+    pub sprinkler_multiplier: f64,
+    pub cabinet_multiplier: f64
+}
+
+/// The base storage quantities per control area from IFC Table
+/// 5003.1.1(1), with its 100% increases for sprinklers and for cabinets.
+impl Default for MaqTable {
+    fn default() -> MaqTable {
+        let limit = |categories: Vec<FireCodeCategory>, gallons: f64| MaqLimit {
+            categories,
+            quantity: Quantity::new(gallons, Unit::Gallon)
+        };
+
+        MaqTable {
+            limits: vec![
+                limit(vec![FireCodeCategory::FlammableLiquidIA], 30.0),
+                limit(vec![FireCodeCategory::FlammableLiquidIA, FireCodeCategory::FlammableLiquidIB,
+                    FireCodeCategory::FlammableLiquidIC], 120.0),
+                limit(vec![FireCodeCategory::CombustibleLiquidII], 120.0),
+                limit(vec![FireCodeCategory::CombustibleLiquidIIIA], 330.0),
+                limit(vec![FireCodeCategory::CombustibleLiquidIIIB], 13_200.0)
+            ],
+            sprinkler_multiplier: 2.0,
+            cabinet_multiplier: 2.0
+        }
+    }
+}
+
+/// A fire-rated area of a building whose quantities are limited together.
+/// `location_id` is the building, room or other location that bounds it.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct ControlArea {
+    pub location_id: LocationId,
+    pub sprinklered: bool
+}
+
+/// How much of one limit a control area uses. Quantities are in the
+/// limit's unit: `on_hand` is everything stored, `in_cabinets` the part of
+/// it in flammable storage cabinets, and `counted` what counts against
+/// `allowed` once the cabinet increase is applied. Containers measured in a
+/// unit that does not convert, such as liquids weighed in grams, are listed
+/// in `uncounted`.
+#[derive(PartialEq, Clone, Debug)]
+pub struct MaqUsage {
+    pub limit: MaqLimit,
+    pub on_hand: Quantity,
+    pub in_cabinets: Quantity,
+    pub counted: Quantity,
+    pub allowed: Quantity,
+    pub uncounted: Vec<InventoryId>
+}
+
+impl MaqUsage {
+    pub fn is_exceeded(&self) -> bool {
+        self.counted.amount > self.allowed.amount
+    }
+
+    /// `counted` as a percentage of `allowed`.
+    pub fn percent_used(&self) -> f64 {
+        if self.allowed.amount > 0.0 {
+            self.counted.amount / self.allowed.amount * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// The usage of every limit of a `MaqTable` in one control area.
+/// `unclassified` lists the active containers of liquids with neither a
+/// flash point nor a flammable liquid statement recorded, which may still
+/// count against a limit; while any are listed the report may understate
+/// the area.
+#[derive(PartialEq, Clone, Debug)]
+pub struct MaqReport {
+    pub usage: Vec<MaqUsage>,
+    pub unclassified: Vec<InventoryId>
+}
+
+/// Totals what remains in the active containers anywhere inside `area`
+/// against each limit of `table`. The chemical of each container is loaded
+/// from `source`, and a missing one is an error.
+pub fn maq_report<S: RecordSource + ?Sized>(area: &ControlArea, table: &MaqTable, source: &S,
+    locations: &[StorageLocation], inventory: &[ChemicalInventory]) -> Result<MaqReport, String>
+{
+    let mut stored = Vec::new();
+    let mut unclassified = Vec::new();
+    for container in inventory_in(area.location_id, locations, inventory).into_iter().filter(|container| container.active) {
+        let record = ChemicalRecord::load(container.chemical_id, source)?;
+        match FireCodeCategory::classify(&record.chemical, &record.hazards) {
+            Some(category) => {
+                let in_cabinet = enclosing_cabinet(container.location_id, locations)
+                    .and_then(|cabinet| cabinet.cabinet_type) == Some(CabinetType::FlammableCabinet);
+                stored.push((category, in_cabinet, container));
+            },
+            None if record.chemical.state_of_matter.phase() == StateOfMatter::Liquid
+                && record.chemical.flash_point.is_none() => unclassified.push(container.id),
+            None => {}
+        }
+    }
+
+    let sprinkler_multiplier = if area.sprinklered { table.sprinkler_multiplier } else { 1.0 };
+    let usage = table.limits.iter()
+        .map(|limit| {
+            let unit = limit.quantity.unit;
+            let mut on_hand = 0.0;
+            let mut in_cabinets = 0.0;
+            let mut uncounted = Vec::new();
+            for &(_, in_cabinet, container) in stored.iter().filter(|&&(category, _, _)| limit.categories.contains(&category)) {
+                match container.remaining_quantity().convert_to(unit) {
+                    Ok(remaining) => {
+                        on_hand += remaining.amount;
+                        if in_cabinet {
+                            in_cabinets += remaining.amount;
+                        }
+                    },
+                    Err(_) => uncounted.push(container.id)
+                }
+            }
+
+            MaqUsage {
+                limit: limit.clone(),
+                on_hand: Quantity::new(on_hand, unit),
+                in_cabinets: Quantity::new(in_cabinets, unit),
+                counted: Quantity::new(on_hand - in_cabinets + in_cabinets / table.cabinet_multiplier, unit),
+                allowed: Quantity::new(limit.quantity.amount * sprinkler_multiplier, unit),
+                uncounted
+            }
+        })
+        .collect();
+    Ok(MaqReport { usage, unclassified })
+}
+
+#[cfg(test)]
+mod fire_code_tests {
+
+    use chemical::{fahrenheit_to_celsius, Chemical};
+    use chemical_inventory::ChemicalInventory;
+    use chemical_record::ChemicalTables;
+    use fire_code::{maq_report, ControlArea, FireCodeCategory, MaqTable};
+    use id::ChemicalId;
+    use quantity::Unit;
+    use state_of_matter::StateOfMatter;
+    use storage_location::{CabinetType, LocationKind, StorageLocation};
+    use test_fixtures::{add, chemical, container, hazards, location};
+
+    use database_lib::interface::{Entry, Value};
+
+    #[test]
+    fn test_classify() {
+        let classify = |state, flash_point, boiling_point|
+            FireCodeCategory::classify(&Chemical { flash_point, boiling_point, ..chemical("Solvent", state) }, &[]);
+        assert_eq!(classify(StateOfMatter::Liquid, Some(-45.0), Some(34.6)), Some(FireCodeCategory::FlammableLiquidIA));
+        assert_eq!(classify(StateOfMatter::Liquid, Some(-20.0), Some(56.0)), Some(FireCodeCategory::FlammableLiquidIB));
+        assert_eq!(classify(StateOfMatter::Liquid, Some(-20.0), None), Some(FireCodeCategory::FlammableLiquidIA));
+        assert_eq!(classify(StateOfMatter::Liquid, Some(30.0), Some(140.0)), Some(FireCodeCategory::FlammableLiquidIC));
+        assert_eq!(classify(StateOfMatter::Liquid, Some(40.0), None), Some(FireCodeCategory::CombustibleLiquidII));
+        assert_eq!(classify(StateOfMatter::Liquid, Some(75.0), None), Some(FireCodeCategory::CombustibleLiquidIIIA));
+        assert_eq!(classify(StateOfMatter::Liquid, Some(200.0), None), Some(FireCodeCategory::CombustibleLiquidIIIB));
+        assert_eq!(classify(StateOfMatter::Liquid, None, Some(56.0)), None);
+        assert_eq!(classify(StateOfMatter::Solid, Some(-20.0), Some(56.0)), None);
+        assert_eq!(FireCodeCategory::FlammableLiquidIB.to_string(), "Class IB flammable liquid");
+    }
+
+    #[test]
+    fn test_classify_boundaries() {
+        let classify = |flash_point: f64, boiling_point: f64| FireCodeCategory::classify(&Chemical {
+            flash_point: Some(fahrenheit_to_celsius(flash_point)),
+            boiling_point: Some(fahrenheit_to_celsius(boiling_point)),
+            ..chemical("Solvent", StateOfMatter::Liquid)
+        }, &[]);
+        assert_eq!(classify(72.9, 99.9), Some(FireCodeCategory::FlammableLiquidIA));
+        assert_eq!(classify(72.9, 100.0), Some(FireCodeCategory::FlammableLiquidIB));
+        assert_eq!(classify(73.0, 150.0), Some(FireCodeCategory::FlammableLiquidIC));
+        assert_eq!(classify(99.9, 150.0), Some(FireCodeCategory::FlammableLiquidIC));
+        assert_eq!(classify(100.0, 300.0), Some(FireCodeCategory::CombustibleLiquidII));
+        assert_eq!(classify(139.9, 300.0), Some(FireCodeCategory::CombustibleLiquidII));
+        assert_eq!(classify(140.0, 300.0), Some(FireCodeCategory::CombustibleLiquidIIIA));
+        assert_eq!(classify(199.9, 300.0), Some(FireCodeCategory::CombustibleLiquidIIIA));
+        assert_eq!(classify(200.0, 300.0), Some(FireCodeCategory::CombustibleLiquidIIIB));
+
+        let mut fields = chemical("Solvent", StateOfMatter::Liquid).get_fields();
+        fields[12] = Value::String("73 °F".to_string());
+        fields[13] = Value::String("100 °F".to_string());
+        let recorded = Chemical::from_fields(&fields).unwrap();
+        assert_eq!(FireCodeCategory::classify(&recorded, &[]), Some(FireCodeCategory::FlammableLiquidIC));
+    }
+
+    #[test]
+    fn test_classify_without_flash_point() {
+        let classify = |state, codes: &[&str]| FireCodeCategory::classify(&chemical("Solvent", state), &hazards(codes));
+        assert_eq!(classify(StateOfMatter::Liquid, &["H224", "H319"]), Some(FireCodeCategory::FlammableLiquidIA));
+        assert_eq!(classify(StateOfMatter::Liquid, &["H225", "H336"]), Some(FireCodeCategory::FlammableLiquidIA));
+        assert_eq!(classify(StateOfMatter::Liquid, &["H226"]), Some(FireCodeCategory::FlammableLiquidIC));
+        assert_eq!(classify(StateOfMatter::Liquid, &["H227"]), Some(FireCodeCategory::CombustibleLiquidIIIA));
+        assert_eq!(classify(StateOfMatter::Liquid, &["H227", "H226"]), Some(FireCodeCategory::FlammableLiquidIC));
+        assert_eq!(classify(StateOfMatter::Liquid, &["H319"]), None);
+        assert_eq!(classify(StateOfMatter::Solid, &["H225"]), None);
+
+        let recorded = Chemical { flash_point: Some(75.0), ..chemical("Solvent", StateOfMatter::Liquid) };
+        assert_eq!(FireCodeCategory::classify(&recorded, &hazards(&["H225"])), Some(FireCodeCategory::CombustibleLiquidIIIA));
+    }
+
+    #[test]
+    fn test_maq_report() {
+        let building = location(LocationKind::Building, "Science Center", None);
+        let lab = location(LocationKind::Room, "204", Some(&building));
+        let cabinet = StorageLocation {
+            cabinet_type: Some(CabinetType::FlammableCabinet),
+            ..location(LocationKind::Cabinet, "A", Some(&lab))
+        };
+        let shelf = location(LocationKind::Shelf, "Top", Some(&cabinet));
+        let other_lab = location(LocationKind::Room, "205", Some(&building));
+        let locations = vec![building, lab.clone(), cabinet, shelf.clone(), other_lab.clone()];
+
+        let mut tables = ChemicalTables::default();
+        let acetone = Chemical { flash_point: Some(-20.0), boiling_point: Some(56.0), ..chemical("Acetone", StateOfMatter::Liquid) };
+        let diesel = Chemical { flash_point: Some(55.0), boiling_point: Some(180.0), ..chemical("Diesel", StateOfMatter::Liquid) };
+        let salt = chemical("Salt", StateOfMatter::Solid);
+        let ethanol = chemical("Ethanol", StateOfMatter::Liquid);
+        let glycerol = chemical("Glycerol", StateOfMatter::Liquid);
+        add(&mut tables, &acetone, &["H225"]);
+        add(&mut tables, &diesel, &["H226"]);
+        add(&mut tables, &salt, &[]);
+        add(&mut tables, &ethanol, &["H225", "H319"]);
+        add(&mut tables, &glycerol, &[]);
+
+        let sized = |chemical: &Chemical, location: &StorageLocation, container_size, unit|
+            ChemicalInventory { container_size, unit, ..container(chemical.id, location.id) };
+        let inventory = vec![
+            sized(&acetone, &lab, 60.0, Unit::Gallon),
+            sized(&acetone, &shelf, 80.0, Unit::Gallon),
+            sized(&acetone, &lab, 500.0, Unit::Gram),
+            sized(&diesel, &lab, 3.785_411_784, Unit::Liter),
+            sized(&salt, &lab, 10.0, Unit::Kilogram),
+            sized(&acetone, &other_lab, 100.0, Unit::Gallon),
+            ChemicalInventory { active: false, ..sized(&acetone, &lab, 50.0, Unit::Gallon) },
+            sized(&ethanol, &lab, 10.0, Unit::Gallon),
+            sized(&glycerol, &lab, 1.0, Unit::Gallon)
+        ];
+
+        let area = ControlArea { location_id: lab.id, sprinklered: false };
+        let report = maq_report(&area, &MaqTable::default(), &tables, &locations, &inventory).unwrap();
+        assert_eq!(report.usage.len(), 5);
+        assert_eq!(report.unclassified, vec![inventory[8].id]);
+
+        let flammable = &report.usage[1];
+        assert_eq!(flammable.on_hand.amount, 150.0);
+        assert_eq!(flammable.in_cabinets.amount, 80.0);
+        assert_eq!(flammable.counted.amount, 110.0);
+        assert_eq!(flammable.allowed.amount, 120.0);
+        assert_eq!(flammable.uncounted, vec![inventory[2].id]);
+        assert!(!flammable.is_exceeded());
+        assert_eq!(report.usage[0].on_hand.amount, 10.0);
+        assert!(!report.usage[0].is_exceeded());
+        assert!((report.usage[2].on_hand.amount - 1.0).abs() < 1e-9);
+
+        let table = MaqTable { cabinet_multiplier: 1.0, ..MaqTable::default() };
+        let report = maq_report(&area, &table, &tables, &locations, &inventory).unwrap();
+        assert!(report.usage[1].is_exceeded());
+
+        let sprinklered = ControlArea { location_id: lab.id, sprinklered: true };
+        let report = maq_report(&sprinklered, &table, &tables, &locations, &inventory).unwrap();
+        assert_eq!(report.usage[1].allowed.amount, 240.0);
+        assert!(!report.usage[1].is_exceeded());
+
+        let unknown = container(ChemicalId::generate(), lab.id);
+        assert!(maq_report(&area, &table, &tables, &locations, &[unknown]).is_err());
+    }
+}
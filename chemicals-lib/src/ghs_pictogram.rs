@@ -0,0 +1,237 @@
+use std::fmt::Display;
+use std::fmt;
+use std::str::FromStr;
+
+use hazard::Hazard;
+use hazard_catalog::HazardClass;
+
+/// The nine GHS pictograms, with their artwork embedded in the crate.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub enum GhsPictogram {
+    ExplodingBomb,
+    Flame,
+    FlameOverCircle,
+    GasCylinder,
+    Corrosion,
+    SkullAndCrossbones,
+    ExclamationMark,
+    HealthHazard,
+    Environment
+}
+
+pub static GHS_PICTOGRAMS: [GhsPictogram; 9] = [
+    GhsPictogram::ExplodingBomb,
+    GhsPictogram::Flame,
+    GhsPictogram::FlameOverCircle,
+    GhsPictogram::GasCylinder,
+    GhsPictogram::Corrosion,
+    GhsPictogram::SkullAndCrossbones,
+    GhsPictogram::ExclamationMark,
+    GhsPictogram::HealthHazard,
+    GhsPictogram::Environment
+];
+
+impl GhsPictogram {
+    pub fn code(&self) -> &'static str {
+        match self {
+            GhsPictogram::ExplodingBomb => "GHS01",
+            GhsPictogram::Flame => "GHS02",
+            GhsPictogram::FlameOverCircle => "GHS03",
+            GhsPictogram::GasCylinder => "GHS04",
+            GhsPictogram::Corrosion => "GHS05",
+            GhsPictogram::SkullAndCrossbones => "GHS06",
+            GhsPictogram::ExclamationMark => "GHS07",
+            GhsPictogram::HealthHazard => "GHS08",
+            GhsPictogram::Environment => "GHS09"
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            GhsPictogram::ExplodingBomb => "Exploding Bomb",
+            GhsPictogram::Flame => "Flame",
+            GhsPictogram::FlameOverCircle => "Flame Over Circle",
+            GhsPictogram::GasCylinder => "Gas Cylinder",
+            GhsPictogram::Corrosion => "Corrosion",
+            GhsPictogram::SkullAndCrossbones => "Skull and Crossbones",
+            GhsPictogram::ExclamationMark => "Exclamation Mark",
+            GhsPictogram::HealthHazard => "Health Hazard",
+            GhsPictogram::Environment => "Environment"
+        }
+    }
+
+    /// The pictogram as a standalone 200x200 SVG document, with a matching
+    /// `viewBox` of `0 0 200 200`.
+    pub fn svg(&self) -> &'static str {
+        match self {
+            GhsPictogram::ExplodingBomb => include_str!("../assets/pictograms/GHS01.svg"),
+            GhsPictogram::Flame => include_str!("../assets/pictograms/GHS02.svg"),
+            GhsPictogram::FlameOverCircle => include_str!("../assets/pictograms/GHS03.svg"),
+            GhsPictogram::GasCylinder => include_str!("../assets/pictograms/GHS04.svg"),
+            GhsPictogram::Corrosion => include_str!("../assets/pictograms/GHS05.svg"),
+            GhsPictogram::SkullAndCrossbones => include_str!("../assets/pictograms/GHS06.svg"),
+            GhsPictogram::ExclamationMark => include_str!("../assets/pictograms/GHS07.svg"),
+            GhsPictogram::HealthHazard => include_str!("../assets/pictograms/GHS08.svg"),
+            GhsPictogram::Environment => include_str!("../assets/pictograms/GHS09.svg")
+        }
+    }
+
+    /// The same artwork as PDF content stream operators, drawn in the SVG's
+    /// coordinates with the y axis pointing down. The streams are converted
+    /// from the SVG files once and stored beside them, so a change to the
+    /// artwork needs both files updated.
+    pub fn pdf_content(&self) -> &'static str {
+        match self {
+            GhsPictogram::ExplodingBomb => include_str!("../assets/pictograms/GHS01.stream"),
+            GhsPictogram::Flame => include_str!("../assets/pictograms/GHS02.stream"),
+            GhsPictogram::FlameOverCircle => include_str!("../assets/pictograms/GHS03.stream"),
+            GhsPictogram::GasCylinder => include_str!("../assets/pictograms/GHS04.stream"),
+            GhsPictogram::Corrosion => include_str!("../assets/pictograms/GHS05.stream"),
+            GhsPictogram::SkullAndCrossbones => include_str!("../assets/pictograms/GHS06.stream"),
+            GhsPictogram::ExclamationMark => include_str!("../assets/pictograms/GHS07.stream"),
+            GhsPictogram::HealthHazard => include_str!("../assets/pictograms/GHS08.stream"),
+            GhsPictogram::Environment => include_str!("../assets/pictograms/GHS09.stream")
+        }
+    }
+}
+
+impl Display for GhsPictogram {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl FromStr for GhsPictogram {
+    type Err = String;
+
+    /// Accepts a GHS code ("GHS02"), a pictogram name ("Flame") or the
+    /// hazard it is commonly called by ("Flammable"), ignoring case, spacing
+    /// and punctuation.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized: String = s.chars()
+            .filter(|c| c.is_alphanumeric())
+            .collect::<String>()
+            .to_lowercase();
+
+        match normalized.as_str() {
+            "ghs01" | "ghs1" | "explodingbomb" | "explosive" | "explosives" => Ok(GhsPictogram::ExplodingBomb),
+            "ghs02" | "ghs2" | "flame" | "flammable" => Ok(GhsPictogram::Flame),
+            "ghs03" | "ghs3" | "flameovercircle" | "oxidizer" | "oxidiser" | "oxidizing" => Ok(GhsPictogram::FlameOverCircle),
+            "ghs04" | "ghs4" | "gascylinder" | "compressedgas" | "gasunderpressure" => Ok(GhsPictogram::GasCylinder),
+            "ghs05" | "ghs5" | "corrosion" | "corrosive" => Ok(GhsPictogram::Corrosion),
+            "ghs06" | "ghs6" | "skullandcrossbones" | "skullcrossbones" | "toxic" | "acutetoxicity" => Ok(GhsPictogram::SkullAndCrossbones),
+            "ghs07" | "ghs7" | "exclamationmark" | "exclamation" | "irritant" | "harmful" => Ok(GhsPictogram::ExclamationMark),
+            "ghs08" | "ghs8" | "healthhazard" => Ok(GhsPictogram::HealthHazard),
+            "ghs09" | "ghs9" | "environment" | "environmental" | "environmentalhazard" => Ok(GhsPictogram::Environment),
+            _ => Err(format!("Unknown GHS pictogram: {}", s))
+        }
+    }
+}
+
+/// The pictograms a label needs for `hazards` together with `linked`, the
+/// pictograms linked to the chemical directly, after the GHS precedence rules:
+/// no exclamation mark next to the skull and crossbones, none for skin or eye
+/// irritation next to corrosion, and none for skin sensitization or
+/// irritation next to a health hazard for respiratory sensitization. A linked
+/// exclamation mark has no hazard class to judge it by, so only the skull and
+/// crossbones supersedes it.
+pub fn required_pictograms(hazards: &[Hazard], linked: &[GhsPictogram]) -> Vec<GhsPictogram> {
+    let entries: Vec<_> = hazards.iter().filter_map(|hazard| hazard.catalog_entry()).collect();
+    let has_class = |class: HazardClass| entries.iter().any(|entry| entry.hazard_class == class);
+    let has_pictogram = |pictogram: GhsPictogram|
+        linked.contains(&pictogram) || entries.iter().any(|entry| entry.pictogram == Some(pictogram));
+
+    let exclamation_superseded = |class: HazardClass| {
+        has_pictogram(GhsPictogram::SkullAndCrossbones)
+            || (has_pictogram(GhsPictogram::Corrosion)
+                && (class == HazardClass::SkinIrritation || class == HazardClass::EyeIrritation))
+            || (has_class(HazardClass::RespiratorySensitization)
+                && (class == HazardClass::SkinSensitization || class == HazardClass::SkinIrritation
+                    || class == HazardClass::EyeIrritation))
+    };
+
+    let mut pictograms: Vec<GhsPictogram> = entries.iter()
+        .filter_map(|entry| match entry.pictogram {
+            Some(GhsPictogram::ExclamationMark) if exclamation_superseded(entry.hazard_class) => None,
+            pictogram => pictogram
+        })
+        .collect();
+    pictograms.extend(linked.iter()
+        .filter(|&&pictogram| pictogram != GhsPictogram::ExclamationMark || !has_pictogram(GhsPictogram::SkullAndCrossbones)));
+    pictograms.sort();
+    pictograms.dedup();
+    pictograms
+}
+
+#[cfg(test)]
+mod ghs_pictogram_tests {
+
+    use std::str::FromStr;
+
+    use ghs_pictogram::{required_pictograms, GhsPictogram, GHS_PICTOGRAMS};
+    use test_fixtures::hazards;
+
+    #[test]
+    fn test_ghspictogram_from_str() {
+        assert_eq!(GhsPictogram::from_str("GHS02"), Ok(GhsPictogram::Flame));
+        assert_eq!(GhsPictogram::from_str("Environmental Hazard"), Ok(GhsPictogram::Environment));
+        assert_eq!(GhsPictogram::from_str("skull & crossbones"), Ok(GhsPictogram::SkullAndCrossbones));
+        assert!(GhsPictogram::from_str("Smiley").is_err());
+    }
+
+    #[test]
+    fn test_ghspictogram_round_trip() {
+        for pictogram in GHS_PICTOGRAMS.iter() {
+            assert_eq!(GhsPictogram::from_str(&pictogram.to_string()), Ok(*pictogram));
+            assert_eq!(GhsPictogram::from_str(pictogram.name()), Ok(*pictogram));
+        }
+    }
+
+    #[test]
+    fn test_ghspictogram_svg() {
+        for pictogram in GHS_PICTOGRAMS.iter() {
+            let svg = pictogram.svg();
+            assert!(svg.starts_with("<svg"));
+            assert!(svg.contains("viewBox=\"0 0 200 200\""));
+            assert!(svg.contains(pictogram.code()));
+        }
+    }
+
+    #[test]
+    fn test_ghspictogram_pdf_content() {
+        for pictogram in GHS_PICTOGRAMS.iter() {
+            let operators: Vec<&str> = pictogram.pdf_content().split_whitespace().collect();
+            assert_eq!(operators.first(), Some(&"q"));
+            assert_eq!(operators.last(), Some(&"Q"));
+            assert_eq!(operators.iter().filter(|&&op| op == "q").count(), operators.iter().filter(|&&op| op == "Q").count());
+        }
+    }
+
+    #[test]
+    fn test_required_pictograms() {
+        assert_eq!(required_pictograms(&hazards(&["H225", "H319", "H336"]), &[]),
+            vec![GhsPictogram::Flame, GhsPictogram::ExclamationMark]);
+        assert_eq!(required_pictograms(&hazards(&["H225"]), &[GhsPictogram::Flame, GhsPictogram::Environment]),
+            vec![GhsPictogram::Flame, GhsPictogram::Environment]);
+        assert!(required_pictograms(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn test_required_pictograms_precedence() {
+        assert_eq!(required_pictograms(&hazards(&["H301", "H302"]), &[]), vec![GhsPictogram::SkullAndCrossbones]);
+        assert_eq!(required_pictograms(&hazards(&["H314", "H315", "H335"]), &[]),
+            vec![GhsPictogram::Corrosion, GhsPictogram::ExclamationMark]);
+        assert_eq!(required_pictograms(&hazards(&["H334", "H317"]), &[]), vec![GhsPictogram::HealthHazard]);
+    }
+
+    #[test]
+    fn test_required_pictograms_precedence_over_linked() {
+        assert_eq!(required_pictograms(&hazards(&["H301"]), &[GhsPictogram::ExclamationMark]),
+            vec![GhsPictogram::SkullAndCrossbones]);
+        assert_eq!(required_pictograms(&hazards(&["H302"]), &[GhsPictogram::SkullAndCrossbones]),
+            vec![GhsPictogram::SkullAndCrossbones]);
+        assert_eq!(required_pictograms(&hazards(&["H315"]), &[GhsPictogram::Corrosion]), vec![GhsPictogram::Corrosion]);
+        assert_eq!(required_pictograms(&hazards(&["H314"]), &[GhsPictogram::ExclamationMark]),
+            vec![GhsPictogram::Corrosion, GhsPictogram::ExclamationMark]);
+    }
+}
@@ -1,67 +1,50 @@
-use std::fmt::Display;
-use std::fmt;
-use std::str::FromStr;
+use hazard_catalog::{hazard_statement, resolve_hazard_statement, HazardStatement};
+use id::HazardId;
+use signal_word::SignalWord;
 
-use database_lib::interface::Entry;
-use database_lib::interface::Value;
-use database_lib::interface::FieldName;
-
-#[derive(Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Entry)]
 pub struct Hazard {
-    pub statement: String
-}
-
-#[derive(PartialEq, Clone, Copy, Debug)]
-pub enum HazardFields {
-    Statement
+    #[field(name = "ID", primary_key)]
+    pub id: HazardId,
+    #[field(name = "Code", parse = "parse_hazard_code", index)]
+    pub code: Option<String>,
+    #[field(name = "Statement")]
+    pub statement: String,
+    #[field(name = "Signal Word")]
+    pub signal_word: SignalWord
 }
 
-impl FieldName for HazardFields {}
-
-impl Display for HazardFields {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            HazardFields::Statement => write!(f, "Statement")
-        }
-    }
-}
-
-impl FromStr for HazardFields {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "Statement" => Ok(HazardFields::Statement),
-            _=> Err("Fields does not exist".to_string())
+/// A blank code is a free-text hazard; any other code must be in the catalog.
+fn parse_hazard_code(code: &str) -> Result<Option<String>, String> {
+    if code.trim().is_empty() {
+        Ok(None)
+    } else {
+        match hazard_statement(code) {
+            Some(entry) => Ok(Some(entry.code.to_string())),
+            None => Err(format!("Unknown hazard statement code: {}", code))
         }
     }
 }
 
-impl Entry for Hazard {
-
-    type FieldNames = HazardFields;
-
-    fn from_fields(values: &[Value]) -> Result<Self, String> {
-        if let Value::String(ref statement) = values[0] {
-            Ok(Hazard {
-                statement: statement.clone()
-            })
-        } else {
-            Err("Incorrect type for statement. Should be String".to_string())
+impl Hazard {
+    /// Builds a hazard from its GHS or EUH code using the built-in catalog.
+    pub fn from_code(code: &str) -> Result<Hazard, String> {
+        match hazard_statement(code) {
+            Some(entry) => Ok(Hazard {
+                id: HazardId::generate(),
+                code: Some(entry.code.to_string()),
+                statement: entry.statement.to_string(),
+                signal_word: entry.signal_word
+            }),
+            None => Err(format!("Unknown hazard statement code: {}", code))
         }
     }
 
-    fn get_field_names() -> Vec<Self::FieldNames> {
-        vec![HazardFields::Statement]
-    }
-
-    fn get_fields(&self) -> Vec<Value> {
-        vec![Value::String(self.statement.clone())]
-    }
-
-    fn get_field(&self, field_name: HazardFields) -> Option<Value> {
-        match field_name {
-            HazardFields::Statement => Some(Value::String(self.statement.clone()))
+    /// The catalog entry for this hazard, from its code or, failing that, its free-text statement.
+    pub fn catalog_entry(&self) -> Option<&'static HazardStatement> {
+        match self.code {
+            Some(ref code) => hazard_statement(code),
+            None => resolve_hazard_statement(&self.statement)
         }
     }
 }
@@ -73,54 +56,113 @@ mod hazard_tests {
     
     use hazard::HazardFields;
     use hazard::Hazard;
+    use signal_word::SignalWord;
+
+    use id::HazardId;
 
     use database_lib::interface::Entry;
     use database_lib::interface::Value;
 
     #[test]
     fn test_hazardfields_from_str() {
+        let hazard_field = HazardFields::from_str("Code");
+        assert_eq!(hazard_field, Ok(HazardFields::Code));
+
         let hazard_field = HazardFields::from_str("Statement");
         assert_eq!(hazard_field, Ok(HazardFields::Statement));
+
+        let hazard_field = HazardFields::from_str("Signal Word");
+        assert_eq!(hazard_field, Ok(HazardFields::SignalWord));
     }
 
     #[test]
     fn test_hazard_from_fields() {
         let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("h318".to_string()),
+            Value::String("Causes serious eye damage".to_string()),
             Value::String("Danger".to_string())
         ];
 
         let hazard = Hazard::from_fields(&fields).unwrap();
 
-        assert_eq!(hazard.statement, "Danger".to_string());
+        assert_eq!(hazard.code, Some("H318".to_string()));
+        assert_eq!(hazard.statement, "Causes serious eye damage".to_string());
+        assert_eq!(hazard.signal_word, SignalWord::Danger);
     }
 
     #[test]
     fn test_hazard_get_field_names() {
         let field_names = Hazard::get_field_names();
 
-        assert_eq!(field_names[0], HazardFields::Statement);
+        assert_eq!(field_names[1], HazardFields::Code);
+        assert_eq!(field_names[2], HazardFields::Statement);
+        assert_eq!(field_names[3], HazardFields::SignalWord);
+        assert_eq!(field_names.len(), 4);
     }
 
     #[test]
     fn test_hazard_get_fields() {
         let hazard = Hazard {
-            statement: "Warning".to_string()
+            id: HazardId::generate(),
+            code: Some("H315".to_string()),
+            statement: "Causes skin irritation".to_string(),
+            signal_word: SignalWord::Warning
         };
 
         let fields = hazard.get_fields();
 
-        assert_eq!(fields[0], Value::String("Warning".to_string()));
-        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[1], Value::String("H315".to_string()));
+        assert_eq!(fields[2], Value::String("Causes skin irritation".to_string()));
+        assert_eq!(fields[3], Value::String("Warning".to_string()));
+        assert_eq!(fields.len(), 4);
     }
 
     #[test]
     fn test_hazard_get_field() {
         let hazard = Hazard {
-            statement: "Peaceful".to_string()
+            id: HazardId::generate(),
+            code: None,
+            statement: "Peaceful".to_string(),
+            signal_word: SignalWord::None
         };
 
         let statement = hazard.get_field(HazardFields::Statement);
 
         assert_eq!(statement, Some(Value::String("Peaceful".to_string())));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_hazard_from_fields_rejects_unknown_code() {
+        let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("H999".to_string()),
+            Value::String("Causes serious eye damage".to_string()),
+            Value::String("Danger".to_string())
+        ];
+
+        assert!(Hazard::from_fields(&fields).is_err());
+    }
+
+    #[test]
+    fn test_hazard_from_code() {
+        let hazard = Hazard::from_code("H225").unwrap();
+
+        assert_eq!(hazard.code, Some("H225".to_string()));
+        assert_eq!(hazard.statement, "Highly flammable liquid and vapor".to_string());
+        assert_eq!(hazard.signal_word, SignalWord::Danger);
+        assert!(Hazard::from_code("H1").is_err());
+    }
+
+    #[test]
+    fn test_hazard_catalog_entry_from_free_text() {
+        let hazard = Hazard {
+            id: HazardId::generate(),
+            code: None,
+            statement: "Causes serious eye irritation.".to_string(),
+            signal_word: SignalWord::Warning
+        };
+
+        assert_eq!(hazard.catalog_entry().unwrap().code, "H319");
+    }
+}
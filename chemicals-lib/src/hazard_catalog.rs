@@ -0,0 +1,322 @@
+use std::fmt::Display;
+use std::fmt;
+
+use ghs_pictogram::GhsPictogram;
+use signal_word::SignalWord;
+use signal_word::SignalWord::{Danger, Warning};
+use signal_word::SignalWord::None as NoSignalWord;
+
+use self::HazardClass::*;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum HazardClass {
+    Explosive,
+    DesensitizedExplosive,
+    FlammableGas,
+    ChemicallyUnstableGas,
+    PyrophoricGas,
+    Aerosol,
+    FlammableLiquid,
+    FlammableSolid,
+    SelfReactiveOrOrganicPeroxide,
+    PyrophoricLiquidOrSolid,
+    SelfHeating,
+    WaterReactive,
+    OxidizingGas,
+    OxidizingLiquidOrSolid,
+    GasUnderPressure,
+    ChemicalUnderPressure,
+    CorrosiveToMetals,
+    AcuteToxicityOral,
+    AcuteToxicityDermal,
+    AcuteToxicityInhalation,
+    AspirationHazard,
+    SkinCorrosion,
+    SkinIrritation,
+    SkinSensitization,
+    SeriousEyeDamage,
+    EyeIrritation,
+    RespiratorySensitization,
+    GermCellMutagenicity,
+    Carcinogenicity,
+    ReproductiveToxicity,
+    SpecificTargetOrganToxicitySingle,
+    SpecificTargetOrganToxicityRepeated,
+    AquaticAcute,
+    AquaticChronic,
+    OzoneLayer,
+    EndocrineDisruptionHuman,
+    EndocrineDisruptionEnvironment,
+    PersistentBioaccumulative,
+    PersistentMobile,
+    Supplemental
+}
+
+impl Display for HazardClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HazardClass::Explosive => write!(f, "Explosives"),
+            HazardClass::DesensitizedExplosive => write!(f, "Desensitized explosives"),
+            HazardClass::FlammableGas => write!(f, "Flammable gases"),
+            HazardClass::ChemicallyUnstableGas => write!(f, "Chemically unstable gases"),
+            HazardClass::PyrophoricGas => write!(f, "Pyrophoric gases"),
+            HazardClass::Aerosol => write!(f, "Aerosols"),
+            HazardClass::FlammableLiquid => write!(f, "Flammable liquids"),
+            HazardClass::FlammableSolid => write!(f, "Flammable solids"),
+            HazardClass::SelfReactiveOrOrganicPeroxide => write!(f, "Self-reactive substances and organic peroxides"),
+            HazardClass::PyrophoricLiquidOrSolid => write!(f, "Pyrophoric liquids and solids"),
+            HazardClass::SelfHeating => write!(f, "Self-heating substances"),
+            HazardClass::WaterReactive => write!(f, "Substances which in contact with water emit flammable gases"),
+            HazardClass::OxidizingGas => write!(f, "Oxidizing gases"),
+            HazardClass::OxidizingLiquidOrSolid => write!(f, "Oxidizing liquids and solids"),
+            HazardClass::GasUnderPressure => write!(f, "Gases under pressure"),
+            HazardClass::ChemicalUnderPressure => write!(f, "Chemicals under pressure"),
+            HazardClass::CorrosiveToMetals => write!(f, "Corrosive to metals"),
+            HazardClass::AcuteToxicityOral => write!(f, "Acute toxicity, oral"),
+            HazardClass::AcuteToxicityDermal => write!(f, "Acute toxicity, dermal"),
+            HazardClass::AcuteToxicityInhalation => write!(f, "Acute toxicity, inhalation"),
+            HazardClass::AspirationHazard => write!(f, "Aspiration hazard"),
+            HazardClass::SkinCorrosion => write!(f, "Skin corrosion"),
+            HazardClass::SkinIrritation => write!(f, "Skin irritation"),
+            HazardClass::SkinSensitization => write!(f, "Skin sensitization"),
+            HazardClass::SeriousEyeDamage => write!(f, "Serious eye damage"),
+            HazardClass::EyeIrritation => write!(f, "Eye irritation"),
+            HazardClass::RespiratorySensitization => write!(f, "Respiratory sensitization"),
+            HazardClass::GermCellMutagenicity => write!(f, "Germ cell mutagenicity"),
+            HazardClass::Carcinogenicity => write!(f, "Carcinogenicity"),
+            HazardClass::ReproductiveToxicity => write!(f, "Reproductive toxicity"),
+            HazardClass::SpecificTargetOrganToxicitySingle => write!(f, "Specific target organ toxicity, single exposure"),
+            HazardClass::SpecificTargetOrganToxicityRepeated => write!(f, "Specific target organ toxicity, repeated exposure"),
+            HazardClass::AquaticAcute => write!(f, "Hazardous to the aquatic environment, acute"),
+            HazardClass::AquaticChronic => write!(f, "Hazardous to the aquatic environment, long-term"),
+            HazardClass::OzoneLayer => write!(f, "Hazardous to the ozone layer"),
+            HazardClass::EndocrineDisruptionHuman => write!(f, "Endocrine disruption for human health"),
+            HazardClass::EndocrineDisruptionEnvironment => write!(f, "Endocrine disruption for the environment"),
+            HazardClass::PersistentBioaccumulative => write!(f, "Persistent, bioaccumulative and toxic"),
+            HazardClass::PersistentMobile => write!(f, "Persistent, mobile and toxic"),
+            HazardClass::Supplemental => write!(f, "Supplemental hazard information")
+        }
+    }
+}
+
+/// One entry of the GHS hazard statement catalog. `category` is the category
+/// the statement is assigned to and `signal_word` the signal word that
+/// category carries; `pictogram` is the GHS pictogram it calls for, if any.
+#[derive(PartialEq, Debug)]
+pub struct HazardStatement {
+    pub code: &'static str,
+    pub statement: &'static str,
+    pub hazard_class: HazardClass,
+    pub category: &'static str,
+    pub signal_word: SignalWord,
+    pub pictogram: Option<GhsPictogram>
+}
+
+const fn entry(code: &'static str, statement: &'static str, hazard_class: HazardClass, category: &'static str,
+    signal_word: SignalWord, pictogram: Option<GhsPictogram>) -> HazardStatement
+{
+    HazardStatement { code, statement, hazard_class, category, signal_word, pictogram }
+}
+
+const GHS01: Option<GhsPictogram> = Some(GhsPictogram::ExplodingBomb);
+const GHS02: Option<GhsPictogram> = Some(GhsPictogram::Flame);
+const GHS03: Option<GhsPictogram> = Some(GhsPictogram::FlameOverCircle);
+const GHS04: Option<GhsPictogram> = Some(GhsPictogram::GasCylinder);
+const GHS05: Option<GhsPictogram> = Some(GhsPictogram::Corrosion);
+const GHS06: Option<GhsPictogram> = Some(GhsPictogram::SkullAndCrossbones);
+const GHS07: Option<GhsPictogram> = Some(GhsPictogram::ExclamationMark);
+const GHS08: Option<GhsPictogram> = Some(GhsPictogram::HealthHazard);
+const GHS09: Option<GhsPictogram> = Some(GhsPictogram::Environment);
+
+/// GHS hazard statements H200 through H420 and the EU supplemental EUH statements.
+pub static HAZARD_STATEMENTS: &[HazardStatement] = &[
+    entry("H200", "Unstable explosive", Explosive, "Unstable explosive", Danger, GHS01),
+    entry("H201", "Explosive; mass explosion hazard", Explosive, "Division 1.1", Danger, GHS01),
+    entry("H202", "Explosive; severe projection hazard", Explosive, "Division 1.2", Danger, GHS01),
+    entry("H203", "Explosive; fire, blast or projection hazard", Explosive, "Division 1.3", Danger, GHS01),
+    entry("H204", "Fire or projection hazard", Explosive, "Division 1.4", Warning, GHS01),
+    entry("H205", "May mass explode in fire", Explosive, "Division 1.5", Danger, None),
+    entry("H206", "Fire, blast or projection hazard; increased risk of explosion if desensitizing agent is reduced", DesensitizedExplosive, "1", Danger, GHS02),
+    entry("H207", "Fire or projection hazard; increased risk of explosion if desensitizing agent is reduced", DesensitizedExplosive, "2", Danger, GHS02),
+    entry("H208", "Fire hazard; increased risk of explosion if desensitizing agent is reduced", DesensitizedExplosive, "4", Warning, GHS02),
+    entry("H220", "Extremely flammable gas", FlammableGas, "1", Danger, GHS02),
+    entry("H221", "Flammable gas", FlammableGas, "2", Warning, None),
+    entry("H222", "Extremely flammable aerosol", Aerosol, "1", Danger, GHS02),
+    entry("H223", "Flammable aerosol", Aerosol, "2", Warning, GHS02),
+    entry("H224", "Extremely flammable liquid and vapor", FlammableLiquid, "1", Danger, GHS02),
+    entry("H225", "Highly flammable liquid and vapor", FlammableLiquid, "2", Danger, GHS02),
+    entry("H226", "Flammable liquid and vapor", FlammableLiquid, "3", Warning, GHS02),
+    entry("H227", "Combustible liquid", FlammableLiquid, "4", Warning, None),
+    entry("H228", "Flammable solid", FlammableSolid, "1", Danger, GHS02),
+    entry("H229", "Pressurized container: may burst if heated", Aerosol, "3", Warning, None),
+    entry("H230", "May react explosively even in the absence of air", ChemicallyUnstableGas, "A", Danger, None),
+    entry("H231", "May react explosively even in the absence of air at elevated pressure and/or temperature", ChemicallyUnstableGas, "B", Danger, None),
+    entry("H232", "May ignite spontaneously if exposed to air", PyrophoricGas, "1", Danger, GHS02),
+    entry("H240", "Heating may cause an explosion", SelfReactiveOrOrganicPeroxide, "Type A", Danger, GHS01),
+    entry("H241", "Heating may cause a fire or explosion", SelfReactiveOrOrganicPeroxide, "Type B", Danger, GHS01),
+    entry("H242", "Heating may cause a fire", SelfReactiveOrOrganicPeroxide, "Type C", Danger, GHS02),
+    entry("H250", "Catches fire spontaneously if exposed to air", PyrophoricLiquidOrSolid, "1", Danger, GHS02),
+    entry("H251", "Self-heating; may catch fire", SelfHeating, "1", Danger, GHS02),
+    entry("H252", "Self-heating in large quantities; may catch fire", SelfHeating, "2", Warning, GHS02),
+    entry("H260", "In contact with water releases flammable gases which may ignite spontaneously", WaterReactive, "1", Danger, GHS02),
+    entry("H261", "In contact with water releases flammable gas", WaterReactive, "2", Danger, GHS02),
+    entry("H270", "May cause or intensify fire; oxidizer", OxidizingGas, "1", Danger, GHS03),
+    entry("H271", "May cause fire or explosion; strong oxidizer", OxidizingLiquidOrSolid, "1", Danger, GHS03),
+    entry("H272", "May intensify fire; oxidizer", OxidizingLiquidOrSolid, "2", Danger, GHS03),
+    entry("H280", "Contains gas under pressure; may explode if heated", GasUnderPressure, "Compressed gas", Warning, GHS04),
+    entry("H281", "Contains refrigerated gas; may cause cryogenic burns or injury", GasUnderPressure, "Refrigerated liquefied gas", Warning, GHS04),
+    entry("H282", "Extremely flammable chemical under pressure: may explode if heated", ChemicalUnderPressure, "1", Danger, GHS02),
+    entry("H283", "Flammable chemical under pressure: may explode if heated", ChemicalUnderPressure, "2", Warning, GHS02),
+    entry("H284", "Chemical under pressure: may explode if heated", ChemicalUnderPressure, "3", Warning, GHS04),
+    entry("H290", "May be corrosive to metals", CorrosiveToMetals, "1", Warning, GHS05),
+    entry("H300", "Fatal if swallowed", AcuteToxicityOral, "1", Danger, GHS06),
+    entry("H301", "Toxic if swallowed", AcuteToxicityOral, "3", Danger, GHS06),
+    entry("H302", "Harmful if swallowed", AcuteToxicityOral, "4", Warning, GHS07),
+    entry("H303", "May be harmful if swallowed", AcuteToxicityOral, "5", Warning, None),
+    entry("H304", "May be fatal if swallowed and enters airways", AspirationHazard, "1", Danger, GHS08),
+    entry("H305", "May be harmful if swallowed and enters airways", AspirationHazard, "2", Warning, GHS08),
+    entry("H310", "Fatal in contact with skin", AcuteToxicityDermal, "1", Danger, GHS06),
+    entry("H311", "Toxic in contact with skin", AcuteToxicityDermal, "3", Danger, GHS06),
+    entry("H312", "Harmful in contact with skin", AcuteToxicityDermal, "4", Warning, GHS07),
+    entry("H313", "May be harmful in contact with skin", AcuteToxicityDermal, "5", Warning, None),
+    entry("H314", "Causes severe skin burns and eye damage", SkinCorrosion, "1", Danger, GHS05),
+    entry("H315", "Causes skin irritation", SkinIrritation, "2", Warning, GHS07),
+    entry("H316", "Causes mild skin irritation", SkinIrritation, "3", Warning, None),
+    entry("H317", "May cause an allergic skin reaction", SkinSensitization, "1", Warning, GHS07),
+    entry("H318", "Causes serious eye damage", SeriousEyeDamage, "1", Danger, GHS05),
+    entry("H319", "Causes serious eye irritation", EyeIrritation, "2A", Warning, GHS07),
+    entry("H320", "Causes eye irritation", EyeIrritation, "2B", Warning, None),
+    entry("H330", "Fatal if inhaled", AcuteToxicityInhalation, "1", Danger, GHS06),
+    entry("H331", "Toxic if inhaled", AcuteToxicityInhalation, "3", Danger, GHS06),
+    entry("H332", "Harmful if inhaled", AcuteToxicityInhalation, "4", Warning, GHS07),
+    entry("H333", "May be harmful if inhaled", AcuteToxicityInhalation, "5", Warning, None),
+    entry("H334", "May cause allergy or asthma symptoms or breathing difficulties if inhaled", RespiratorySensitization, "1", Danger, GHS08),
+    entry("H335", "May cause respiratory irritation", SpecificTargetOrganToxicitySingle, "3", Warning, GHS07),
+    entry("H336", "May cause drowsiness or dizziness", SpecificTargetOrganToxicitySingle, "3", Warning, GHS07),
+    entry("H340", "May cause genetic defects", GermCellMutagenicity, "1", Danger, GHS08),
+    entry("H341", "Suspected of causing genetic defects", GermCellMutagenicity, "2", Warning, GHS08),
+    entry("H350", "May cause cancer", Carcinogenicity, "1", Danger, GHS08),
+    entry("H351", "Suspected of causing cancer", Carcinogenicity, "2", Warning, GHS08),
+    entry("H360", "May damage fertility or the unborn child", ReproductiveToxicity, "1", Danger, GHS08),
+    entry("H361", "Suspected of damaging fertility or the unborn child", ReproductiveToxicity, "2", Warning, GHS08),
+    entry("H362", "May cause harm to breast-fed children", ReproductiveToxicity, "Effects on or via lactation", NoSignalWord, None),
+    entry("H370", "Causes damage to organs", SpecificTargetOrganToxicitySingle, "1", Danger, GHS08),
+    entry("H371", "May cause damage to organs", SpecificTargetOrganToxicitySingle, "2", Warning, GHS08),
+    entry("H372", "Causes damage to organs through prolonged or repeated exposure", SpecificTargetOrganToxicityRepeated, "1", Danger, GHS08),
+    entry("H373", "May cause damage to organs through prolonged or repeated exposure", SpecificTargetOrganToxicityRepeated, "2", Warning, GHS08),
+    entry("H400", "Very toxic to aquatic life", AquaticAcute, "1", Warning, GHS09),
+    entry("H401", "Toxic to aquatic life", AquaticAcute, "2", NoSignalWord, None),
+    entry("H402", "Harmful to aquatic life", AquaticAcute, "3", NoSignalWord, None),
+    entry("H410", "Very toxic to aquatic life with long lasting effects", AquaticChronic, "1", Warning, GHS09),
+    entry("H411", "Toxic to aquatic life with long lasting effects", AquaticChronic, "2", NoSignalWord, GHS09),
+    entry("H412", "Harmful to aquatic life with long lasting effects", AquaticChronic, "3", NoSignalWord, None),
+    entry("H413", "May cause long lasting harmful effects to aquatic life", AquaticChronic, "4", NoSignalWord, None),
+    entry("H420", "Harms public health and the environment by destroying ozone in the upper atmosphere", OzoneLayer, "1", Warning, GHS07),
+    entry("EUH001", "Explosive when dry", Supplemental, "", NoSignalWord, None),
+    entry("EUH014", "Reacts violently with water", Supplemental, "", NoSignalWord, None),
+    entry("EUH018", "In use may form flammable/explosive vapor-air mixture", Supplemental, "", NoSignalWord, None),
+    entry("EUH019", "May form explosive peroxides", Supplemental, "", NoSignalWord, None),
+    entry("EUH029", "Contact with water liberates toxic gas", Supplemental, "", NoSignalWord, None),
+    entry("EUH031", "Contact with acids liberates toxic gas", Supplemental, "", NoSignalWord, None),
+    entry("EUH032", "Contact with acids liberates very toxic gas", Supplemental, "", NoSignalWord, None),
+    entry("EUH044", "Risk of explosion if heated under confinement", Supplemental, "", NoSignalWord, None),
+    entry("EUH066", "Repeated exposure may cause skin dryness or cracking", Supplemental, "", NoSignalWord, None),
+    entry("EUH070", "Toxic by eye contact", Supplemental, "", NoSignalWord, None),
+    entry("EUH071", "Corrosive to the respiratory tract", Supplemental, "", NoSignalWord, None),
+    entry("EUH201", "Contains lead. Should not be used on surfaces liable to be chewed or sucked by children", Supplemental, "", NoSignalWord, None),
+    entry("EUH201A", "Warning! Contains lead", Supplemental, "", NoSignalWord, None),
+    entry("EUH202", "Cyanoacrylate. Danger. Bonds skin and eyes in seconds. Keep out of the reach of children", Supplemental, "", NoSignalWord, None),
+    entry("EUH203", "Contains chromium (VI). May produce an allergic reaction", Supplemental, "", NoSignalWord, None),
+    entry("EUH204", "Contains isocyanates. May produce an allergic reaction", Supplemental, "", NoSignalWord, None),
+    entry("EUH205", "Contains epoxy constituents. May produce an allergic reaction", Supplemental, "", NoSignalWord, None),
+    entry("EUH206", "Warning! Do not use together with other products. May release dangerous gases (chlorine)", Supplemental, "", NoSignalWord, None),
+    entry("EUH207", "Warning! Contains cadmium. Dangerous fumes are formed during use. See information supplied by the manufacturer. Comply with the safety instructions", Supplemental, "", NoSignalWord, None),
+    entry("EUH208", "Contains (name of sensitizing substance). May produce an allergic reaction", Supplemental, "", NoSignalWord, None),
+    entry("EUH209", "Can become highly flammable in use", Supplemental, "", NoSignalWord, None),
+    entry("EUH209A", "Can become flammable in use", Supplemental, "", NoSignalWord, None),
+    entry("EUH210", "Safety data sheet available on request", Supplemental, "", NoSignalWord, None),
+    entry("EUH211", "Warning! Hazardous respirable droplets may be formed when sprayed. Do not breathe spray or mist", Supplemental, "", NoSignalWord, None),
+    entry("EUH212", "Warning! Hazardous respirable dust may be formed when used. Do not breathe dust", Supplemental, "", NoSignalWord, None),
+    entry("EUH380", "May cause endocrine disruption in humans", EndocrineDisruptionHuman, "1", Danger, None),
+    entry("EUH381", "Suspected of causing endocrine disruption in humans", EndocrineDisruptionHuman, "2", Warning, None),
+    entry("EUH401", "To avoid risks to human health and the environment, comply with the instructions for use", Supplemental, "", NoSignalWord, None),
+    entry("EUH430", "May cause endocrine disruption in the environment", EndocrineDisruptionEnvironment, "1", Danger, None),
+    entry("EUH431", "Suspected of causing endocrine disruption in the environment", EndocrineDisruptionEnvironment, "2", Warning, None),
+    entry("EUH440", "Accumulates in the environment and living organisms including in humans", PersistentBioaccumulative, "PBT", Danger, None),
+    entry("EUH441", "Strongly accumulates in the environment and living organisms including in humans", PersistentBioaccumulative, "vPvB", Danger, None),
+    entry("EUH450", "Can cause long-lasting and diffuse contamination of water resources", PersistentMobile, "PMT", Danger, None),
+    entry("EUH451", "Can cause very long-lasting and diffuse contamination of water resources", PersistentMobile, "vPvM", Danger, None)
+];
+
+/// Looks up a hazard statement by its code, ignoring case and surrounding whitespace.
+pub fn hazard_statement(code: &str) -> Option<&'static HazardStatement> {
+    let code = code.trim().to_uppercase();
+    HAZARD_STATEMENTS.iter().find(|entry| entry.code == code)
+}
+
+/// Reduces a statement to lowercase words so spelling and punctuation
+/// variants ("vapour", "Vapor.", "sensitising") compare equal.
+fn normalize_statement(statement: &str) -> String {
+    statement.to_lowercase()
+        .replace("vapour", "vapor")
+        .replace("sensitis", "sensitiz")
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Resolves free text back to its catalog entry. The text may be a bare code
+/// ("H225"), start with one ("H225: Highly flammable..."), or be the
+/// statement itself in any capitalization, punctuation or spelling variant.
+pub fn resolve_hazard_statement(text: &str) -> Option<&'static HazardStatement> {
+    let leading_code = text.trim()
+        .split(|c: char| c.is_whitespace() || c == ':' || c == '-')
+        .next()
+        .and_then(hazard_statement);
+    if leading_code.is_some() {
+        return leading_code;
+    }
+
+    let normalized = normalize_statement(text);
+    HAZARD_STATEMENTS.iter().find(|entry| normalize_statement(entry.statement) == normalized)
+}
+
+#[cfg(test)]
+mod hazard_catalog_tests {
+
+    use ghs_pictogram::GhsPictogram;
+    use hazard_catalog::{hazard_statement, resolve_hazard_statement, HazardClass, HAZARD_STATEMENTS};
+    use signal_word::SignalWord;
+
+    #[test]
+    fn test_hazard_statement_lookup() {
+        let h225 = hazard_statement("H225").unwrap();
+        assert_eq!(h225.statement, "Highly flammable liquid and vapor");
+        assert_eq!(h225.hazard_class, HazardClass::FlammableLiquid);
+        assert_eq!(h225.category, "2");
+        assert_eq!(h225.signal_word, SignalWord::Danger);
+        assert_eq!(h225.pictogram, Some(GhsPictogram::Flame));
+
+        assert_eq!(hazard_statement(" euh014 ").unwrap().code, "EUH014");
+        assert!(hazard_statement("H999").is_none());
+    }
+
+    #[test]
+    fn test_hazard_statement_codes_are_unique() {
+        for (i, entry) in HAZARD_STATEMENTS.iter().enumerate() {
+            assert!(HAZARD_STATEMENTS[i + 1..].iter().all(|other| other.code != entry.code), "{} is duplicated", entry.code);
+        }
+    }
+
+    #[test]
+    fn test_resolve_hazard_statement() {
+        assert_eq!(resolve_hazard_statement("Highly flammable liquid and vapour.").unwrap().code, "H225");
+        assert_eq!(resolve_hazard_statement("causes serious eye irritation").unwrap().code, "H319");
+        assert_eq!(resolve_hazard_statement("H314: Causes severe skin burns").unwrap().code, "H314");
+        assert_eq!(resolve_hazard_statement("H335").unwrap().code, "H335");
+        assert!(resolve_hazard_statement("Smells funny").is_none());
+    }
+}
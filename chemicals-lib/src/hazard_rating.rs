@@ -0,0 +1,419 @@
+use std::fmt::Display;
+use std::fmt;
+use std::str::FromStr;
+
+use chemical::Chemical;
+use chemical_inventory::ChemicalInventory;
+use chemical_record::{ChemicalRecord, RecordSource};
+use error::ChemicalsError;
+use fire_code::FireCodeCategory;
+use hazard::Hazard;
+use hazard_catalog::{HazardClass, HazardStatement};
+use id::{ChemicalId, LocationId};
+use storage_location::{inventory_in, StorageLocation};
+
+/// The highest value of any NFPA 704 or HMIS rating.
+const MAX_RATING: u8 = 4;
+
+/// A symbol for the bottom quadrant of the NFPA 704 diamond.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub enum NfpaSpecial {
+    WaterReactive,
+    Oxidizer,
+    SimpleAsphyxiant
+}
+
+impl Display for NfpaSpecial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NfpaSpecial::WaterReactive => write!(f, "W"),
+            NfpaSpecial::Oxidizer => write!(f, "OX"),
+            NfpaSpecial::SimpleAsphyxiant => write!(f, "SA")
+        }
+    }
+}
+
+impl FromStr for NfpaSpecial {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_uppercase().as_str() {
+            "W" => Ok(NfpaSpecial::WaterReactive),
+            "OX" | "OXY" => Ok(NfpaSpecial::Oxidizer),
+            "SA" => Ok(NfpaSpecial::SimpleAsphyxiant),
+            _ => Err(format!("Unknown NFPA 704 special hazard: {}", s))
+        }
+    }
+}
+
+/// Reads `count` ratings from 0 to 4 separated by `-` or `/`. A rating may
+/// carry a trailing `*` if `asterisk` allows it on that position.
+fn parse_ratings(s: &str, count: usize, asterisk: Option<usize>) -> Result<(Vec<u8>, bool), String> {
+    let parts: Vec<&str> = s.split(['-', '/']).map(|part| part.trim()).collect();
+    if parts.len() != count {
+        return Err(format!("Expected {} ratings: {}", count, s));
+    }
+
+    let mut starred = false;
+    let mut ratings = Vec::new();
+    for (i, part) in parts.iter().enumerate() {
+        let digits = match part.strip_suffix('*') {
+            Some(digits) if asterisk == Some(i) => {
+                starred = true;
+                digits
+            },
+            _ => part
+        };
+        match digits.parse::<u8>() {
+            Ok(rating) if rating <= MAX_RATING => ratings.push(rating),
+            _ => return Err(format!("Invalid rating {} in {}", part, s))
+        }
+    }
+    Ok((ratings, starred))
+}
+
+/// An NFPA 704 diamond: health, flammability and instability from 0 to 4
+/// and any special hazards, written `3-2-1 W OX`.
+#[derive(PartialEq, Eq, Hash, Clone, Debug, Default)]
+pub struct NfpaRating {
+    pub health: u8,
+    pub flammability: u8,
+    pub instability: u8,
+    pub special: Vec<NfpaSpecial>
+}
+
+impl NfpaRating {
+    /// The highest of each rating and every special hazard across `ratings`,
+    /// as a placard covering all of them shows. All zeros if there are none.
+    pub fn most_severe<'a, I>(ratings: I) -> NfpaRating
+        where I: IntoIterator<Item = &'a NfpaRating>
+    {
+        let mut combined = ratings.into_iter().fold(NfpaRating::default(), |mut combined, rating| {
+            combined.health = combined.health.max(rating.health);
+            combined.flammability = combined.flammability.max(rating.flammability);
+            combined.instability = combined.instability.max(rating.instability);
+            combined.special.extend(rating.special.iter().cloned());
+            combined
+        });
+        combined.special.sort();
+        combined.special.dedup();
+        combined
+    }
+}
+
+impl Display for NfpaRating {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}-{}", self.health, self.flammability, self.instability)?;
+        for special in self.special.iter() {
+            write!(f, " {}", special)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for NfpaRating {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+        let (ratings, _) = parse_ratings(words.next().unwrap_or(""), 3, None)?;
+        let mut special = words.map(NfpaSpecial::from_str).collect::<Result<Vec<NfpaSpecial>, String>>()?;
+        special.sort();
+        special.dedup();
+
+        Ok(NfpaRating {
+            health: ratings[0],
+            flammability: ratings[1],
+            instability: ratings[2],
+            special
+        })
+    }
+}
+
+/// An HMIS label: health, flammability and physical hazard from 0 to 4,
+/// with `chronic` for the asterisk marking a chronic health hazard, written
+/// `2*-3-0`.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub struct HmisRating {
+    pub health: u8,
+    pub chronic: bool,
+    pub flammability: u8,
+    pub physical: u8
+}
+
+impl Display for HmisRating {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let chronic = if self.chronic { "*" } else { "" };
+        write!(f, "{}{}-{}-{}", self.health, chronic, self.flammability, self.physical)
+    }
+}
+
+impl FromStr for HmisRating {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (ratings, chronic) = parse_ratings(s.trim(), 3, Some(0))?;
+        Ok(HmisRating {
+            health: ratings[0],
+            chronic,
+            flammability: ratings[1],
+            physical: ratings[2]
+        })
+    }
+}
+
+/// Acute health rating for a hazard statement.
+fn health_rating(entry: &HazardStatement) -> u8 {
+    match entry.code {
+        "H300" | "H310" | "H330" => 4,
+        "H301" | "H311" | "H331" | "H314" | "H318" | "H370" | "H281" => 3,
+        "H302" | "H312" | "H332" | "H334" | "H371" => 2,
+        "H303" | "H313" | "H333" | "H304" | "H305" | "H315" | "H316" | "H317" | "H319" | "H320" | "H335" | "H336" => 1,
+        _ => 0
+    }
+}
+
+/// Whether a hazard statement is a chronic health effect, which HMIS marks
+/// with an asterisk and NFPA 704 leaves out.
+fn is_chronic(entry: &HazardStatement) -> bool {
+    matches!(entry.hazard_class, HazardClass::GermCellMutagenicity | HazardClass::Carcinogenicity
+        | HazardClass::ReproductiveToxicity | HazardClass::SpecificTargetOrganToxicityRepeated
+        | HazardClass::RespiratorySensitization)
+}
+
+fn flammability_rating(entry: &HazardStatement) -> u8 {
+    match entry.code {
+        "H220" | "H222" | "H224" | "H232" | "H250" | "H282" => 4,
+        "H221" | "H223" | "H225" | "H226" | "H228" | "H251" | "H283" => 3,
+        "H227" | "H252" => 2,
+        _ => 0
+    }
+}
+
+/// Flammability from flash point and boiling point, which fix the rating
+/// more precisely than the GHS category does.
+fn liquid_flammability_rating(chemical: &Chemical, hazards: &[Hazard]) -> u8 {
+    match FireCodeCategory::classify(chemical, hazards) {
+        Some(FireCodeCategory::FlammableLiquidIA) => 4,
+        Some(FireCodeCategory::FlammableLiquidIB) | Some(FireCodeCategory::FlammableLiquidIC) => 3,
+        Some(FireCodeCategory::CombustibleLiquidII) | Some(FireCodeCategory::CombustibleLiquidIIIA) => 2,
+        Some(FireCodeCategory::CombustibleLiquidIIIB) => 1,
+        None => 0
+    }
+}
+
+fn instability_rating(entry: &HazardStatement) -> u8 {
+    match entry.code {
+        "H200" | "H201" | "H202" | "H203" | "H240" => 4,
+        "H204" | "H205" | "H206" | "H230" | "H241" | "EUH001" => 3,
+        "H207" | "H208" | "H231" | "H242" | "H260" => 2,
+        "H261" | "EUH014" | "EUH029" => 1,
+        _ => 0
+    }
+}
+
+/// HMIS physical hazard beyond instability: reactions with water, oxidizers
+/// and pressurized containers.
+fn physical_rating(entry: &HazardStatement) -> u8 {
+    match entry.code {
+        "H260" | "H270" | "H271" => 3,
+        "H261" | "H272" | "EUH014" => 2,
+        "H229" | "H280" | "H281" | "H284" => 1,
+        _ => instability_rating(entry)
+    }
+}
+
+fn special_hazard(entry: &HazardStatement) -> Option<NfpaSpecial> {
+    match entry.code {
+        "H260" | "H261" | "EUH014" | "EUH029" => Some(NfpaSpecial::WaterReactive),
+        "H270" | "H271" | "H272" => Some(NfpaSpecial::Oxidizer),
+        _ => None
+    }
+}
+
+fn catalog_entries(hazards: &[Hazard]) -> Vec<&'static HazardStatement> {
+    hazards.iter().filter_map(|hazard| hazard.catalog_entry()).collect()
+}
+
+/// The NFPA 704 ratings `hazards` suggest for `chemical`, each the highest
+/// any hazard calls for. Flammability also follows the chemical's flash
+/// point and boiling point when they are recorded. No GHS statement maps to
+/// the simple asphyxiant symbol, so it is never suggested.
+pub fn suggest_nfpa_rating(chemical: &Chemical, hazards: &[Hazard]) -> NfpaRating {
+    let entries = catalog_entries(hazards);
+    let highest = |rating: fn(&HazardStatement) -> u8| entries.iter().map(|&entry| rating(entry)).max().unwrap_or(0);
+
+    let mut special: Vec<NfpaSpecial> = entries.iter().filter_map(|&entry| special_hazard(entry)).collect();
+    special.sort();
+    special.dedup();
+
+    NfpaRating {
+        health: highest(health_rating),
+        flammability: highest(flammability_rating).max(liquid_flammability_rating(chemical, hazards)),
+        instability: highest(instability_rating),
+        special
+    }
+}
+
+/// The HMIS ratings `hazards` suggest for `chemical`, on the same scale as
+/// `suggest_nfpa_rating`, with chronic effects marked and water reactivity,
+/// oxidizers and pressure counted as physical hazards.
+pub fn suggest_hmis_rating(chemical: &Chemical, hazards: &[Hazard]) -> HmisRating {
+    let entries = catalog_entries(hazards);
+    let nfpa = suggest_nfpa_rating(chemical, hazards);
+
+    HmisRating {
+        health: nfpa.health,
+        chronic: entries.iter().any(|&entry| is_chronic(entry)),
+        flammability: nfpa.flammability,
+        physical: entries.iter().map(|&entry| physical_rating(entry)).max().unwrap_or(0)
+    }
+}
+
+/// The NFPA 704 placard for a door, and the chemicals that could not be
+/// rated for it.
+#[derive(PartialEq, Clone, Debug)]
+pub struct DoorPlacard {
+    pub rating: NfpaRating,
+    /// Why each chemical left out of `rating` could not be rated: a missing
+    /// chemical row, or a link to a missing row where no rating is stored.
+    /// While any are listed the placard may understate the room.
+    pub unrated: Vec<ChemicalsError>
+}
+
+/// The NFPA 704 placard for the door of `location_id`: the most severe
+/// rating of every chemical with an active container anywhere inside it.
+/// A chemical with a stored rating uses it; one without uses the rating its
+/// hazards suggest. A chemical that cannot be loaded from `source` is listed
+/// in `unrated` instead of failing the placard; only a storage error does.
+pub fn door_placard<S: RecordSource + ?Sized>(location_id: LocationId, source: &S, locations: &[StorageLocation],
+    inventory: &[ChemicalInventory]) -> Result<DoorPlacard, ChemicalsError>
+{
+    let mut chemical_ids: Vec<ChemicalId> = Vec::new();
+    for container in inventory_in(location_id, locations, inventory).into_iter().filter(|container| container.active) {
+        if !chemical_ids.contains(&container.chemical_id) {
+            chemical_ids.push(container.chemical_id);
+        }
+    }
+
+    let mut ratings = Vec::new();
+    let mut unrated = Vec::new();
+    for chemical_id in chemical_ids {
+        let rating = match source.chemical(chemical_id)?.and_then(|chemical| chemical.nfpa_rating) {
+            Some(rating) => Ok(rating),
+            None => ChemicalRecord::load(chemical_id, source).map(|record| record.nfpa_rating())
+        };
+        match rating {
+            Ok(rating) => ratings.push(rating),
+            Err(error @ ChemicalsError::NotFound(_)) => unrated.push(error),
+            Err(error) => return Err(error)
+        }
+    }
+
+    Ok(DoorPlacard {
+        rating: NfpaRating::most_severe(ratings.iter()),
+        unrated
+    })
+}
+
+#[cfg(test)]
+mod hazard_rating_tests {
+
+    use std::str::FromStr;
+
+    use chemical::Chemical;
+    use chemical_inventory::ChemicalInventory;
+    use chemical_record::ChemicalTables;
+    use hazard_rating::{door_placard, suggest_hmis_rating, suggest_nfpa_rating, HmisRating, NfpaRating, NfpaSpecial};
+    use state_of_matter::StateOfMatter;
+    use storage_location::LocationKind;
+    use test_fixtures::{add, chemical, container, hazards, location};
+
+    #[test]
+    fn test_nfparating_from_str() {
+        let rating = NfpaRating::from_str("3-0-2 OX W").unwrap();
+        assert_eq!(rating, NfpaRating {
+            health: 3,
+            flammability: 0,
+            instability: 2,
+            special: vec![NfpaSpecial::WaterReactive, NfpaSpecial::Oxidizer]
+        });
+        assert_eq!(rating.to_string(), "3-0-2 W OX");
+        assert_eq!(NfpaRating::from_str("1/3/0").unwrap().to_string(), "1-3-0");
+        assert!(NfpaRating::from_str("5-0-0").is_err());
+        assert!(NfpaRating::from_str("1-3").is_err());
+        assert!(NfpaRating::from_str("1-3-0 COR").is_err());
+    }
+
+    #[test]
+    fn test_hmisrating_from_str() {
+        let rating = HmisRating::from_str("2*-3-0").unwrap();
+        assert!(rating.chronic);
+        assert_eq!((rating.health, rating.flammability, rating.physical), (2, 3, 0));
+        assert_eq!(rating.to_string(), "2*-3-0");
+        assert!(HmisRating::from_str("2-3*-0").is_err());
+    }
+
+    #[test]
+    fn test_suggest_ratings() {
+        let acetone = Chemical { flash_point: Some(-20.0), boiling_point: Some(56.0), ..chemical("Acetone", StateOfMatter::Liquid) };
+        assert_eq!(suggest_nfpa_rating(&acetone, &hazards(&["H225", "H319", "H336"])).to_string(), "1-3-0");
+
+        let ether = Chemical { flash_point: Some(-45.0), boiling_point: Some(34.6), ..chemical("Diethyl ether", StateOfMatter::Liquid) };
+        assert_eq!(suggest_nfpa_rating(&ether, &hazards(&["H225"])).flammability, 4);
+
+        let sodium = chemical("Sodium", StateOfMatter::Solid);
+        assert_eq!(suggest_nfpa_rating(&sodium, &hazards(&["H260", "H314", "EUH014"])).to_string(), "3-0-2 W");
+
+        let nitric_acid = chemical("Nitric acid", StateOfMatter::Liquid);
+        let nitric_hazards = hazards(&["H272", "H290", "H314", "H331"]);
+        assert_eq!(suggest_nfpa_rating(&nitric_acid, &nitric_hazards).to_string(), "3-0-0 OX");
+        assert_eq!(suggest_hmis_rating(&nitric_acid, &nitric_hazards), HmisRating {
+            health: 3,
+            chronic: false,
+            flammability: 0,
+            physical: 2
+        });
+
+        let benzene = Chemical { flash_point: Some(-11.0), boiling_point: Some(80.1), ..chemical("Benzene", StateOfMatter::Liquid) };
+        let benzene_hazards = hazards(&["H225", "H304", "H315", "H319", "H340", "H350", "H372"]);
+        assert_eq!(suggest_hmis_rating(&benzene, &benzene_hazards).to_string(), "1*-3-0");
+    }
+
+    #[test]
+    fn test_door_placard() {
+        let mut tables = ChemicalTables::default();
+        let acetone = Chemical { flash_point: Some(-20.0), boiling_point: Some(56.0), ..chemical("Acetone", StateOfMatter::Liquid) };
+        let sodium = Chemical { nfpa_rating: Some(NfpaRating::from_str("3-1-2 W").unwrap()), ..chemical("Sodium", StateOfMatter::Solid) };
+        let cyanide = chemical("Sodium cyanide", StateOfMatter::Solid);
+        add(&mut tables, &acetone, &["H225", "H319"]);
+        add(&mut tables, &sodium, &[]);
+        add(&mut tables, &cyanide, &[]);
+
+        let building = location(LocationKind::Building, "Science Center", None);
+        let room = location(LocationKind::Room, "204", Some(&building));
+        let shelf = location(LocationKind::Shelf, "Top", Some(&room));
+        let other_room = location(LocationKind::Room, "205", Some(&building));
+        let locations = vec![building, room.clone(), shelf.clone(), other_room];
+
+        tables.chemicals[2].nfpa_rating = Some(NfpaRating::from_str("4-0-0").unwrap());
+        let inventory = vec![
+            container(acetone.id, shelf.id),
+            container(sodium.id, room.id),
+            ChemicalInventory { active: false, ..container(cyanide.id, room.id) }
+        ];
+
+        let placard = door_placard(room.id, &tables, &locations, &inventory).unwrap();
+        assert_eq!(placard.rating.to_string(), "3-3-2 W");
+        assert!(placard.unrated.is_empty());
+        assert_eq!(door_placard(locations[3].id, &tables, &locations, &inventory).unwrap().rating, NfpaRating::default());
+
+        // A dangling hazard link leaves acetone off the placard but still
+        // rates sodium, whose rating is stored.
+        tables.hazards.clear();
+        let placard = door_placard(room.id, &tables, &locations, &inventory).unwrap();
+        assert_eq!(placard.rating.to_string(), "3-1-2 W");
+        assert_eq!(placard.unrated.len(), 1);
+        assert!(placard.unrated[0].to_string().contains("does not exist"));
+    }
+}
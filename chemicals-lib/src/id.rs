@@ -0,0 +1,100 @@
+use std::fmt::Display;
+use std::fmt;
+use std::str::FromStr;
+
+use uuid::Uuid;
+
+macro_rules! entity_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+        pub struct $name(Uuid);
+
+        impl $name {
+            /// A fresh random identifier for a row about to be inserted.
+            pub fn generate() -> $name {
+                $name(Uuid::new_v4())
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}", self.0.hyphenated())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = String;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Uuid::parse_str(s.trim())
+                    .map($name)
+                    .map_err(|_| format!("Invalid {}: {}", stringify!($name), s))
+            }
+        }
+    };
+}
+
+entity_id!(
+    /// Identifies a `Chemical` row.
+    ChemicalId);
+entity_id!(
+    /// Identifies a `ChemicalInventory` row, i.e. a single container.
+    InventoryId);
+entity_id!(
+    /// Identifies a `StorageLocation` row.
+    LocationId);
+entity_id!(
+    /// Identifies a `Component` row.
+    ComponentId);
+entity_id!(
+    /// Identifies a `Hazard` row.
+    HazardId);
+entity_id!(
+    /// Identifies a `Precaution` row.
+    PrecautionId);
+entity_id!(
+    /// Identifies a `Pictogram` row.
+    PictogramId);
+entity_id!(
+    /// Identifies a `Manufacturer` row.
+    ManufacturerId);
+entity_id!(
+    /// Identifies a `ManufacturerChemical` row.
+    ManufacturerChemicalId);
+entity_id!(
+    /// Identifies a `ChemicalComponent` row.
+    ChemicalComponentId);
+entity_id!(
+    /// Identifies a `ChemicalHazard` row.
+    ChemicalHazardId);
+entity_id!(
+    /// Identifies a `ChemicalPrecaution` row.
+    ChemicalPrecautionId);
+entity_id!(
+    /// Identifies a `ChemicalPictogram` row.
+    ChemicalPictogramId);
+
+#[cfg(test)]
+mod id_tests {
+
+    use std::str::FromStr;
+
+    use id::{ChemicalId, InventoryId};
+
+    #[test]
+    fn test_id_round_trip() {
+        let id = ChemicalId::generate();
+        assert_eq!(ChemicalId::from_str(&id.to_string()), Ok(id));
+        assert_ne!(ChemicalId::generate(), id);
+    }
+
+    #[test]
+    fn test_id_from_str() {
+        let id = InventoryId::from_str("6f1c2a9e-3b4d-4e8f-9a0b-1c2d3e4f5a6b").unwrap();
+        assert_eq!(id.to_string(), "6f1c2a9e-3b4d-4e8f-9a0b-1c2d3e4f5a6b");
+        assert_eq!(InventoryId::from_str(" 6f1c2a9e-3b4d-4e8f-9a0b-1c2d3e4f5a6b "), Ok(id));
+        assert!(InventoryId::from_str("").is_err());
+        assert!(InventoryId::from_str("bottle 7").is_err());
+    }
+}
@@ -0,0 +1,377 @@
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use serde::de::{DeserializeOwned, Error};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use database_lib::interface;
+
+use acid_base::AcidBase;
+use cas_number::CasNumber;
+use chemical::Chemical;
+use chemical_component::ChemicalComponent;
+use chemical_hazard::ChemicalHazard;
+use chemical_inventory::ChemicalInventory;
+use chemical_pictogram::ChemicalPictogram;
+use chemical_precaution::ChemicalPrecaution;
+use component::Component;
+use container_type::{ContainerType, CONTAINER_TYPES};
+use entry::ChemicalsEntry;
+use ghs_pictogram::{GhsPictogram, GHS_PICTOGRAMS};
+use hazard::Hazard;
+use hazard_rating::{HmisRating, NfpaRating};
+use id::{ChemicalComponentId, ChemicalHazardId, ChemicalId, ChemicalPictogramId, ChemicalPrecautionId, ComponentId,
+    HazardId, InventoryId, LocationId, ManufacturerChemicalId, ManufacturerId, PictogramId, PrecautionId};
+use manufacturer::Manufacturer;
+use manufacturer_chemical::ManufacturerChemical;
+use pictogram::Pictogram;
+use precaution::Precaution;
+use quantity::Unit;
+use shelf_life::LifeSpan;
+use signal_word::SignalWord;
+use state_of_matter::StateOfMatter;
+use storage_location::{CabinetType, LocationKind, StorageLocation};
+
+/// The JSON Schema of a single field, used by the schema `#[derive(Entry)]`
+/// generates for each entity.
+pub trait FieldSchema {
+    /// Whether an object must have the field. Only optional fields, which
+    /// read as `None` when left out, may be missing.
+    const REQUIRED: bool = true;
+
+    fn field_schema() -> Value;
+}
+
+fn schema(pairs: &[(&str, Value)]) -> Value {
+    let mut map = Map::new();
+    for &(key, ref value) in pairs {
+        map.insert(key.to_string(), value.clone());
+    }
+    Value::Object(map)
+}
+
+fn string_schema(key: &str, value: Value) -> Value {
+    schema(&[("type", Value::from("string")), (key, value)])
+}
+
+fn enum_schema<T: ToString>(values: &[T]) -> Value {
+    string_schema("enum", values.iter().map(|value| Value::from(value.to_string())).collect())
+}
+
+impl FieldSchema for String {
+    fn field_schema() -> Value {
+        schema(&[("type", Value::from("string"))])
+    }
+}
+
+impl FieldSchema for bool {
+    fn field_schema() -> Value {
+        schema(&[("type", Value::from("boolean"))])
+    }
+}
+
+impl FieldSchema for f64 {
+    fn field_schema() -> Value {
+        schema(&[("type", Value::from("number"))])
+    }
+}
+
+impl FieldSchema for NaiveDate {
+    fn field_schema() -> Value {
+        string_schema("format", Value::from("date"))
+    }
+}
+
+/// `None` is written as `null`, and a missing field reads as `None`.
+impl<T: FieldSchema> FieldSchema for Option<T> {
+    const REQUIRED: bool = false;
+
+    fn field_schema() -> Value {
+        schema(&[("anyOf", Value::Array(vec![T::field_schema(), schema(&[("type", Value::from("null"))])]))])
+    }
+}
+
+impl FieldSchema for CasNumber {
+    fn field_schema() -> Value {
+        string_schema("pattern", Value::from("^[0-9]{2,7}-[0-9]{2}-[0-9]$"))
+    }
+}
+
+impl FieldSchema for LifeSpan {
+    fn field_schema() -> Value {
+        string_schema("pattern", Value::from("^(Indefinite|[0-9]+ (day|week|month|year)s?)$"))
+    }
+}
+
+impl FieldSchema for NfpaRating {
+    fn field_schema() -> Value {
+        string_schema("pattern", Value::from("^[0-4]-[0-4]-[0-4]( (W|OX|SA))*$"))
+    }
+}
+
+impl FieldSchema for HmisRating {
+    fn field_schema() -> Value {
+        string_schema("pattern", Value::from("^[0-4]\\*?-[0-4]-[0-4]$"))
+    }
+}
+
+impl FieldSchema for GhsPictogram {
+    fn field_schema() -> Value {
+        enum_schema(&GHS_PICTOGRAMS)
+    }
+}
+
+impl FieldSchema for AcidBase {
+    fn field_schema() -> Value {
+        enum_schema(&[AcidBase::Acid, AcidBase::Base])
+    }
+}
+
+impl FieldSchema for SignalWord {
+    fn field_schema() -> Value {
+        enum_schema(&[SignalWord::None, SignalWord::Warning, SignalWord::Danger])
+    }
+}
+
+impl FieldSchema for StateOfMatter {
+    fn field_schema() -> Value {
+        enum_schema(&[StateOfMatter::Solid, StateOfMatter::Powder, StateOfMatter::Liquid, StateOfMatter::CryogenicLiquid,
+            StateOfMatter::Gas, StateOfMatter::CompressedGas, StateOfMatter::Aerosol])
+    }
+}
+
+impl FieldSchema for Unit {
+    fn field_schema() -> Value {
+        enum_schema(&[Unit::Milligram, Unit::Gram, Unit::Kilogram, Unit::Ounce, Unit::Pound, Unit::Milliliter,
+            Unit::Liter, Unit::FluidOunce, Unit::Pint, Unit::Quart, Unit::Gallon, Unit::Each])
+    }
+}
+
+/// The known kinds are listed as examples only, as any other description
+/// is kept as written.
+impl FieldSchema for ContainerType {
+    fn field_schema() -> Value {
+        schema(&[("type", Value::from("string")), ("minLength", Value::from(1)),
+            ("examples", CONTAINER_TYPES.iter().map(|container| Value::from(container.to_string())).collect())])
+    }
+}
+
+impl FieldSchema for LocationKind {
+    fn field_schema() -> Value {
+        enum_schema(&[LocationKind::Building, LocationKind::Room, LocationKind::Cabinet, LocationKind::Shelf])
+    }
+}
+
+impl FieldSchema for CabinetType {
+    fn field_schema() -> Value {
+        enum_schema(&[CabinetType::FlammableCabinet, CabinetType::CorrosiveCabinet, CabinetType::Refrigerator,
+            CabinetType::ExplosionProofRefrigerator, CabinetType::GasCage])
+    }
+}
+
+/// Types written as their `Display` text and read back with `FromStr`,
+/// the same representation their database column uses.
+macro_rules! string_serde {
+    ($($ty:ty),*) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    <$ty>::from_str(&String::deserialize(deserializer)?).map_err(D::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+string_serde!(AcidBase, CabinetType, CasNumber, ContainerType, GhsPictogram, HmisRating, LifeSpan, LocationKind, NfpaRating, SignalWord, StateOfMatter, Unit,
+    ChemicalComponentId, ChemicalHazardId, ChemicalId, ChemicalPictogramId, ChemicalPrecautionId, ComponentId,
+    HazardId, InventoryId, LocationId, ManufacturerChemicalId, ManufacturerId, PictogramId, PrecautionId);
+
+macro_rules! id_schema {
+    ($($ty:ty),*) => {
+        $(
+            impl FieldSchema for $ty {
+                fn field_schema() -> Value {
+                    string_schema("format", Value::from("uuid"))
+                }
+            }
+        )*
+    };
+}
+
+id_schema!(ChemicalComponentId, ChemicalHazardId, ChemicalId, ChemicalPictogramId, ChemicalPrecautionId, ComponentId,
+    HazardId, InventoryId, LocationId, ManufacturerChemicalId, ManufacturerId, PictogramId, PrecautionId);
+
+/// The schema of one entity: an object keyed by its column names, each
+/// given with its schema and whether it is required.
+pub fn entry_schema(title: &str, fields: Vec<(&str, Value, bool)>) -> Value {
+    let required = fields.iter().filter(|&&(_, _, required)| required).map(|&(name, _, _)| Value::from(name)).collect();
+    let mut properties = Map::new();
+    for (name, field, _) in fields {
+        properties.insert(name.to_string(), field);
+    }
+
+    schema(&[
+        ("title", Value::from(title)),
+        ("type", Value::from("object")),
+        ("properties", Value::Object(properties)),
+        ("required", Value::Array(required)),
+        ("additionalProperties", Value::Bool(false))
+    ])
+}
+
+/// The JSON Schema document for every entity, each under `definitions`
+/// by its type name. `schema/chemicals.schema.json` is this, pretty-printed.
+pub fn json_schema() -> Value {
+    let entries = [
+        Chemical::json_schema(),
+        ChemicalComponent::json_schema(),
+        ChemicalHazard::json_schema(),
+        ChemicalInventory::json_schema(),
+        ChemicalPictogram::json_schema(),
+        ChemicalPrecaution::json_schema(),
+        Component::json_schema(),
+        Hazard::json_schema(),
+        Manufacturer::json_schema(),
+        ManufacturerChemical::json_schema(),
+        Pictogram::json_schema(),
+        Precaution::json_schema(),
+        StorageLocation::json_schema()
+    ];
+
+    let mut definitions = Map::new();
+    for entry in entries.iter() {
+        definitions.insert(entry["title"].as_str().unwrap_or_default().to_string(), entry.clone());
+    }
+
+    schema(&[
+        ("$schema", Value::from("http://json-schema.org/draft-07/schema#")),
+        ("title", Value::from("chemicals-lib")),
+        ("definitions", Value::Object(definitions))
+    ])
+}
+
+/// Reads a new `T` from JSON. An object with no primary key, or a null or
+/// blank one, is given a freshly generated key; anything else is read as by
+/// `Deserialize`, which, like `from_fields`, requires a valid key.
+pub fn create_from_json<T: ChemicalsEntry + DeserializeOwned>(mut json: Value) -> Result<T, serde_json::Error> {
+    if let Value::Object(ref mut object) = json {
+        let blank = match object.get(T::PRIMARY_KEY) {
+            None | Some(Value::Null) => true,
+            Some(Value::String(key)) => key.trim().is_empty(),
+            Some(_) => false
+        };
+        if blank {
+            if let interface::Value::String(key) = T::new_key() {
+                object.insert(T::PRIMARY_KEY.to_string(), Value::from(key));
+            }
+        }
+    }
+    serde_json::from_value(json)
+}
+
+#[cfg(test)]
+mod json_tests {
+
+    use chrono::NaiveDate;
+    use serde_json;
+
+    use chemical_inventory::ChemicalInventory;
+    use container_type::ContainerType;
+    use hazard::Hazard;
+    use id::{ChemicalId, InventoryId, LocationId};
+    use json::{create_from_json, json_schema};
+    use quantity::Unit;
+
+    fn inventory() -> ChemicalInventory {
+        ChemicalInventory {
+            id: "5f8c8f4e-2a44-4c4e-8f0b-3a1e6c9d7b21".parse::<InventoryId>().unwrap(),
+            chemical_id: "0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10".parse::<ChemicalId>().unwrap(),
+            location_id: "3c9d2e7a-1f4b-4d8e-a6c5-7b0e9f2d1a43".parse::<LocationId>().unwrap(),
+            lot_number: "12".to_string(),
+            purchase_date: NaiveDate::from_ymd_opt(2018, 12, 1),
+            arrival_date: NaiveDate::from_ymd_opt(2018, 12, 3),
+            open_date: None,
+            expiration_date: None,
+            disposal_date: None,
+            removal_date: None,
+            disposal_method: "".to_string(),
+            active: true,
+            container_type: ContainerType::Bottle,
+            container_size: 3.0,
+            unit: Unit::Gallon,
+            percent_remaining: 50.0
+        }
+    }
+
+    #[test]
+    fn test_entity_json_uses_field_names() {
+        let json = serde_json::to_value(inventory()).unwrap();
+        assert_eq!(json["Chemical ID"], "0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10");
+        assert_eq!(json["Purchase Date"], "2018-12-01");
+        assert_eq!(json["Open Date"], serde_json::Value::Null);
+        assert_eq!(json["Unit"], "gal");
+        assert_eq!(json["Percent Remaining"], 50.0);
+        assert_eq!(serde_json::from_value::<ChemicalInventory>(json).unwrap(), inventory());
+    }
+
+    #[test]
+    fn test_deserialize_validates() {
+        let mut json = serde_json::to_value(inventory()).unwrap();
+        json["Open Date"] = "2018-11-01".into();
+        assert!(serde_json::from_value::<ChemicalInventory>(json).is_err());
+
+        let mut json = serde_json::to_value(Hazard::from_code("H225").unwrap()).unwrap();
+        json["Code"] = "h225".into();
+        assert_eq!(serde_json::from_value::<Hazard>(json.clone()).unwrap().code, Some("H225".to_string()));
+        json["Code"] = "H999".into();
+        assert!(serde_json::from_value::<Hazard>(json).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_requires_non_optional_fields() {
+        let mut json = serde_json::to_value(inventory()).unwrap();
+        json.as_object_mut().unwrap().remove("Arrival Date");
+        assert_eq!(serde_json::from_value::<ChemicalInventory>(json.clone()).unwrap().arrival_date, None);
+
+        json.as_object_mut().unwrap().remove("Lot Number");
+        assert!(serde_json::from_value::<ChemicalInventory>(json).is_err());
+
+        let schema = json_schema();
+        let required = schema["definitions"]["ChemicalInventory"]["required"].as_array().unwrap();
+        assert!(required.contains(&"ID".into()));
+        assert!(required.contains(&"Lot Number".into()));
+        assert!(!required.contains(&"Arrival Date".into()));
+    }
+
+    #[test]
+    fn test_create_from_json_generates_missing_ids() {
+        let mut json = serde_json::to_value(inventory()).unwrap();
+        json.as_object_mut().unwrap().remove("ID");
+        assert!(serde_json::from_value::<ChemicalInventory>(json.clone()).is_err());
+        let created = create_from_json::<ChemicalInventory>(json.clone()).unwrap();
+        assert!(created.id != inventory().id);
+        assert_eq!(created.lot_number, "12".to_string());
+
+        json["ID"] = "".into();
+        assert!(serde_json::from_value::<ChemicalInventory>(json.clone()).is_err());
+        assert!(create_from_json::<ChemicalInventory>(json.clone()).is_ok());
+
+        json["ID"] = "5f8c8f4e-2a44-4c4e-8f0b-3a1e6c9d7b21".into();
+        assert_eq!(create_from_json::<ChemicalInventory>(json).unwrap(), inventory());
+    }
+
+    #[test]
+    fn test_json_schema_is_up_to_date() {
+        let generated = serde_json::to_string_pretty(&json_schema()).unwrap() + "\n";
+        assert!(generated == include_str!("../schema/chemicals.schema.json"),
+            "schema/chemicals.schema.json is stale; regenerate it with the json_schema example");
+    }
+}
@@ -0,0 +1,666 @@
+use std::fmt::Display;
+use std::fmt;
+use std::str::FromStr;
+
+use chemical_record::ChemicalRecord;
+use ghs_pictogram::{required_pictograms, GhsPictogram};
+use signal_word::SignalWord;
+
+/// Labels are laid out in points, the unit of both SVG user space here and PDF.
+const POINTS_PER_INCH: f64 = 72.0;
+/// Blank border left around the contents of a label.
+const MARGIN: f64 = 9.0;
+/// Space between pictograms and between sections of a label.
+const GAP: f64 = 4.0;
+/// The smallest body text tried before a label is judged not to fit.
+const MIN_FONT_SIZE: f64 = 4.0;
+/// Size of the product identifier and signal word relative to the body text.
+const HEADING_SCALE: f64 = 1.6;
+const LINE_SPACING: f64 = 1.2;
+/// Width and height of the square every pictogram's artwork is drawn in.
+const PICTOGRAM_ARTWORK_SIZE: f64 = 200.0;
+
+/// Advance widths of Helvetica for the printable ASCII characters, in
+/// thousandths of the font size.
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556,
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556,
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556,
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584
+];
+
+/// Advance widths of Helvetica-Bold, as for `HELVETICA_WIDTHS`.
+const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611,
+    975, 722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722, 611, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 333, 278, 333, 584, 556,
+    333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556, 278, 889, 611, 611,
+    611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584
+];
+
+/// Label stock the renderer lays out for. `FourBySix` is 4 in wide and 6 in
+/// tall; `TwoByFour` is 4 in wide and 2 in tall.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum LabelSize {
+    FourBySix,
+    TwoByFour
+}
+
+impl LabelSize {
+    /// Width in points.
+    pub fn width(&self) -> f64 {
+        4.0 * POINTS_PER_INCH
+    }
+
+    /// Height in points.
+    pub fn height(&self) -> f64 {
+        match self {
+            LabelSize::FourBySix => 6.0 * POINTS_PER_INCH,
+            LabelSize::TwoByFour => 2.0 * POINTS_PER_INCH
+        }
+    }
+
+    /// The body text size a label starts at before shrinking to fit.
+    fn max_font_size(&self) -> f64 {
+        match self {
+            LabelSize::FourBySix => 10.0,
+            LabelSize::TwoByFour => 7.0
+        }
+    }
+
+    fn max_pictogram_size(&self) -> f64 {
+        match self {
+            LabelSize::FourBySix => POINTS_PER_INCH,
+            LabelSize::TwoByFour => POINTS_PER_INCH / 2.0
+        }
+    }
+}
+
+impl Display for LabelSize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LabelSize::FourBySix => write!(f, "4x6"),
+            LabelSize::TwoByFour => write!(f, "2x4")
+        }
+    }
+}
+
+impl FromStr for LabelSize {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized: String = s.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_lowercase();
+        match normalized.as_str() {
+            "4x6" | "6x4" => Ok(LabelSize::FourBySix),
+            "2x4" | "4x2" => Ok(LabelSize::TwoByFour),
+            _ => Err(format!("Unknown label size: {}", s))
+        }
+    }
+}
+
+/// The contents of a GHS label for one chemical.
+#[derive(PartialEq, Debug, Clone)]
+pub struct GhsLabel {
+    pub product_identifier: String,
+    /// Components with their CAS numbers, listed after the product identifier.
+    pub ingredients: Vec<String>,
+    pub signal_word: SignalWord,
+    pub pictograms: Vec<GhsPictogram>,
+    pub hazard_statements: Vec<String>,
+    pub precautionary_statements: Vec<String>,
+    /// Name, address and phone number of each manufacturer.
+    pub suppliers: Vec<String>
+}
+
+impl GhsLabel {
+    /// Gathers the label for `record`. The signal word and pictograms are the
+    /// ones its hazards call for, with the pictograms linked directly merged
+    /// in before the GHS precedence rules drop any that are superseded; a
+    /// linked pictogram that is not a GHS pictogram is an error, as there is
+    /// no artwork to print for it.
+    pub fn from_record(record: &ChemicalRecord) -> Result<GhsLabel, String> {
+        let linked = record.pictograms.iter()
+            .map(|pictogram| pictogram.ghs_pictogram())
+            .collect::<Result<Vec<_>, _>>()?;
+        let pictograms = required_pictograms(&record.hazards, &linked);
+
+        let ingredients = record.components.iter().map(|component| {
+            let component = &component.component;
+            match component.cas_number {
+                Some(ref cas_number) => format!("{} ({})", component.chemical_name, cas_number),
+                None => component.chemical_name.clone()
+            }
+        }).collect();
+
+        let mut hazard_statements = Vec::new();
+        for hazard in record.hazards.iter() {
+            push_unique(&mut hazard_statements, statement_text(&hazard.code, &hazard.statement));
+        }
+
+        let mut precautionary_statements = Vec::new();
+        for precaution in record.precautions.iter() {
+            push_unique(&mut precautionary_statements, statement_text(&precaution.code, &precaution.statement));
+        }
+
+        let suppliers = record.manufacturers.iter().map(|manufacturer| {
+            let manufacturer = &manufacturer.manufacturer;
+            let contact: Vec<&str> = [&manufacturer.company_name, &manufacturer.address, &manufacturer.phone_number].iter()
+                .map(|part| part.trim())
+                .filter(|part| !part.is_empty())
+                .collect();
+            contact.join(", ")
+        }).filter(|supplier| !supplier.is_empty()).collect();
+
+        Ok(GhsLabel {
+            product_identifier: record.chemical.chemical_name.clone(),
+            ingredients,
+            signal_word: record.signal_word(),
+            pictograms,
+            hazard_statements,
+            precautionary_statements,
+            suppliers
+        })
+    }
+
+    /// The label as a standalone SVG document sized in inches.
+    pub fn to_svg(&self, size: LabelSize) -> Result<String, String> {
+        let layout = self.layout(size)?;
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {} {}\" width=\"{}in\" height=\"{}in\">\n",
+            number(layout.width), number(layout.height),
+            number(layout.width / POINTS_PER_INCH), number(layout.height / POINTS_PER_INCH));
+        svg.push_str(&format!("  <title>{}</title>\n", escape_xml(&self.product_identifier)));
+        svg.push_str(&format!("  <rect x=\"0\" y=\"0\" width=\"{}\" height=\"{}\" fill=\"#ffffff\"/>\n",
+            number(layout.width), number(layout.height)));
+
+        for placed in layout.pictograms.iter() {
+            let artwork = number(PICTOGRAM_ARTWORK_SIZE);
+            svg.push_str(&format!("  <svg x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n    {}\n  </svg>\n",
+                number(placed.x), number(placed.y), number(placed.size), number(placed.size), artwork, artwork,
+                svg_body(placed.pictogram.svg())));
+        }
+
+        for placed in layout.texts.iter() {
+            let weight = if placed.bold { " font-weight=\"bold\"" } else { "" };
+            svg.push_str(&format!("  <text x=\"{}\" y=\"{}\" font-family=\"Helvetica, Arial, sans-serif\" font-size=\"{}\"{}>{}</text>\n",
+                number(placed.x), number(placed.y), number(placed.size), weight, escape_xml(&placed.text)));
+        }
+
+        svg.push_str("</svg>\n");
+        Ok(svg)
+    }
+
+    /// The label as a single-page PDF using the standard Helvetica fonts, so
+    /// nothing needs to be embedded.
+    pub fn to_pdf(&self, size: LabelSize) -> Result<Vec<u8>, String> {
+        let layout = self.layout(size)?;
+        let mut content = Vec::new();
+
+        for placed in layout.pictograms.iter() {
+            // The artwork's y axis points down and PDF's points up, so it is
+            // flipped as it is scaled into the placed square.
+            let scale = placed.size / PICTOGRAM_ARTWORK_SIZE;
+            content.extend_from_slice(format!("q {} 0 0 {} {} {} cm\n", coefficient(scale), coefficient(-scale),
+                number(placed.x), number(layout.height - placed.y)).as_bytes());
+            content.extend_from_slice(placed.pictogram.pdf_content().as_bytes());
+            content.extend_from_slice(b"Q\n");
+        }
+
+        for placed in layout.texts.iter() {
+            let font = if placed.bold { "F2" } else { "F1" };
+            content.extend_from_slice(format!("BT /{} {} Tf {} {} Td ", font, number(placed.size), number(placed.x),
+                number(layout.height - placed.y)).as_bytes());
+            content.extend_from_slice(&pdf_string(&placed.text));
+            content.extend_from_slice(b" Tj ET\n");
+        }
+
+        Ok(pdf_document(layout.width, layout.height, &content))
+    }
+
+    /// Lays the label out at the largest body text size that fits on `size`.
+    fn layout(&self, size: LabelSize) -> Result<Layout, String> {
+        let mut font_size = size.max_font_size();
+        while font_size >= MIN_FONT_SIZE {
+            let layout = self.layout_at(size, font_size);
+            if layout.cursor <= size.height() - MARGIN {
+                return Ok(layout);
+            }
+            font_size -= 0.5;
+        }
+        Err(format!("The label for {} does not fit on a {} label", self.product_identifier, size))
+    }
+
+    fn layout_at(&self, size: LabelSize, font_size: f64) -> Layout {
+        let heading_size = font_size * HEADING_SCALE;
+        let mut layout = Layout::new(size);
+
+        layout.paragraph(&self.product_identifier, heading_size, true);
+        if !self.ingredients.is_empty() {
+            layout.paragraph(&format!("Contains: {}", self.ingredients.join(", ")), font_size, false);
+        }
+        layout.cursor += GAP;
+
+        layout.pictogram_row(&self.pictograms, size.max_pictogram_size());
+        if self.signal_word != SignalWord::None {
+            layout.paragraph(&self.signal_word.to_string(), heading_size, true);
+        }
+
+        for statement in self.hazard_statements.iter() {
+            layout.paragraph(statement, font_size, false);
+        }
+        layout.cursor += GAP;
+        for statement in self.precautionary_statements.iter() {
+            layout.paragraph(statement, font_size, false);
+        }
+
+        if !self.suppliers.is_empty() {
+            layout.cursor += GAP;
+            for supplier in self.suppliers.iter() {
+                layout.paragraph(&format!("Supplier: {}", supplier), font_size, false);
+            }
+        }
+        layout
+    }
+}
+
+/// A line of text on a label, `y` being its baseline measured from the top.
+#[derive(Debug)]
+struct PlacedText {
+    text: String,
+    x: f64,
+    y: f64,
+    size: f64,
+    bold: bool
+}
+
+/// A pictogram on a label, `x` and `y` being its top left corner.
+#[derive(Debug)]
+struct PlacedPictogram {
+    pictogram: GhsPictogram,
+    x: f64,
+    y: f64,
+    size: f64
+}
+
+/// Everything placed on a label, top to bottom, with `cursor` at the top of
+/// the next free line.
+#[derive(Debug)]
+struct Layout {
+    width: f64,
+    height: f64,
+    cursor: f64,
+    texts: Vec<PlacedText>,
+    pictograms: Vec<PlacedPictogram>
+}
+
+impl Layout {
+    fn new(size: LabelSize) -> Layout {
+        Layout {
+            width: size.width(),
+            height: size.height(),
+            cursor: MARGIN,
+            texts: Vec::new(),
+            pictograms: Vec::new()
+        }
+    }
+
+    fn paragraph(&mut self, text: &str, size: f64, bold: bool) {
+        for line in wrap(text, self.width - 2.0 * MARGIN, size, bold) {
+            self.texts.push(PlacedText {
+                text: line,
+                x: MARGIN,
+                y: self.cursor + size,
+                size,
+                bold
+            });
+            self.cursor += size * LINE_SPACING;
+        }
+    }
+
+    /// Centers `pictograms` in a row, shrinking them below `max_size` if
+    /// there are too many to fit across.
+    fn pictogram_row(&mut self, pictograms: &[GhsPictogram], max_size: f64) {
+        if pictograms.is_empty() {
+            return;
+        }
+
+        let count = pictograms.len() as f64;
+        let size = max_size.min((self.width - 2.0 * MARGIN - GAP * (count - 1.0)) / count);
+        let start = (self.width - size * count - GAP * (count - 1.0)) / 2.0;
+        for (i, pictogram) in pictograms.iter().enumerate() {
+            self.pictograms.push(PlacedPictogram {
+                pictogram: *pictogram,
+                x: start + (size + GAP) * i as f64,
+                y: self.cursor,
+                size
+            });
+        }
+        self.cursor += size + GAP;
+    }
+}
+
+fn push_unique(statements: &mut Vec<String>, statement: String) {
+    if !statements.contains(&statement) {
+        statements.push(statement);
+    }
+}
+
+fn statement_text(code: &Option<String>, statement: &str) -> String {
+    match *code {
+        Some(ref code) => format!("{} {}", code, statement),
+        None => statement.to_string()
+    }
+}
+
+fn char_width(c: char, bold: bool) -> u32 {
+    let widths = if bold { &HELVETICA_BOLD_WIDTHS } else { &HELVETICA_WIDTHS };
+    match c {
+        ' '..='~' => u32::from(widths[c as usize - 32]),
+        _ => 556
+    }
+}
+
+fn text_width(text: &str, size: f64, bold: bool) -> f64 {
+    f64::from(text.chars().map(|c| char_width(c, bold)).sum::<u32>()) * size / 1000.0
+}
+
+/// Breaks `text` into lines no wider than `width`, between words where it
+/// can and inside a word too long for a line of its own.
+fn wrap(text: &str, width: f64, size: f64, bold: bool) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let joined = if line.is_empty() { word.to_string() } else { format!("{} {}", line, word) };
+        if text_width(&joined, size, bold) <= width {
+            line = joined;
+            continue;
+        }
+
+        if !line.is_empty() {
+            lines.push(line);
+            line = String::new();
+        }
+        for c in word.chars() {
+            line.push(c);
+            if line.chars().count() > 1 && text_width(&line, size, bold) > width {
+                line.pop();
+                lines.push(line);
+                line = c.to_string();
+            }
+        }
+    }
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// A coordinate or size with at most two decimals and no trailing zeros.
+fn number(n: f64) -> String {
+    decimals(n, 2)
+}
+
+/// A transform coefficient, which needs more precision than a coordinate
+/// because it multiplies every coordinate after it.
+fn coefficient(n: f64) -> String {
+    decimals(n, 4)
+}
+
+fn decimals(n: f64, places: usize) -> String {
+    let text = format!("{:.*}", places, n);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" { "0".to_string() } else { text.to_string() }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// The elements of a pictogram's artwork without its enclosing `<svg>` tag
+/// or anything before it, such as an XML declaration.
+fn svg_body(svg: &str) -> &str {
+    let start = svg.find("<svg").and_then(|start| svg[start..].find('>').map(|end| start + end + 1)).unwrap_or(0);
+    let end = svg.rfind("</svg>").unwrap_or(svg.len());
+    svg[start..end].trim()
+}
+
+/// `text` as a PDF string in WinAnsiEncoding, the encoding the standard
+/// fonts are declared with. Characters it lacks print as `?`.
+fn pdf_string(text: &str) -> Vec<u8> {
+    let mut bytes = vec![b'('];
+    for c in text.chars() {
+        let byte = match c {
+            '(' | ')' | '\\' => {
+                bytes.push(b'\\');
+                c as u8
+            },
+            ' '..='~' | '\u{a0}'..='\u{ff}' => c as u8,
+            '\u{2026}' => 0x85,
+            '\u{2018}' => 0x91,
+            '\u{2019}' => 0x92,
+            '\u{201c}' => 0x93,
+            '\u{201d}' => 0x94,
+            '\u{2022}' => 0x95,
+            '\u{2013}' => 0x96,
+            '\u{2014}' => 0x97,
+            _ => b'?'
+        };
+        bytes.push(byte);
+    }
+    bytes.push(b')');
+    bytes
+}
+
+/// A one page PDF with `content` as its page description, Helvetica as
+/// `/F1` and Helvetica-Bold as `/F2`.
+fn pdf_document(width: f64, height: f64, content: &[u8]) -> Vec<u8> {
+    let mut objects = vec![
+        b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+        b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_vec(),
+        format!("<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /Font << /F1 4 0 R /F2 5 0 R >> >> /Contents 6 0 R >>",
+            number(width), number(height)).into_bytes(),
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>".to_vec(),
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>".to_vec()
+    ];
+    let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+    stream.extend_from_slice(content);
+    stream.extend_from_slice(b"\nendstream");
+    objects.push(stream);
+
+    let mut pdf = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+    let mut offsets = Vec::new();
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n", i + 1).as_bytes());
+        pdf.extend_from_slice(object);
+        pdf.extend_from_slice(b"\nendobj\n");
+    }
+
+    let xref = pdf.len();
+    pdf.extend_from_slice(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes());
+    for offset in offsets {
+        pdf.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+    }
+    pdf.extend_from_slice(format!("trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n", objects.len() + 1, xref).as_bytes());
+    pdf
+}
+
+#[cfg(test)]
+mod label_tests {
+
+    use std::str::FromStr;
+
+    use cas_number::CasNumber;
+    use chemical::Chemical;
+    use chemical_component::ConcentrationRange;
+    use chemical_record::{ChemicalRecord, RecordComponent, RecordManufacturer};
+    use component::Component;
+    use ghs_pictogram::GhsPictogram;
+    use id::{ChemicalId, ComponentId, ManufacturerId};
+    use label::{text_width, wrap, GhsLabel, LabelSize};
+    use manufacturer::Manufacturer;
+    use pictogram::Pictogram;
+    use precaution::Precaution;
+    use shelf_life::LifeSpan;
+    use signal_word::SignalWord;
+    use state_of_matter::StateOfMatter;
+    use test_fixtures;
+
+    fn record() -> ChemicalRecord {
+        ChemicalRecord {
+            chemical: Chemical {
+                id: ChemicalId::generate(),
+                chemical_name: "Isopropyl Alcohol & Water".to_string(),
+                purpose: "Cleaning".to_string(),
+                state_of_matter: StateOfMatter::Liquid,
+                msds_sds_path: "IPA SDS".to_string(),
+                qr_code: "1001".to_string(),
+                opened_life_span: Some(LifeSpan::Years(1)),
+                unopened_life_span: Some(LifeSpan::Years(3)),
+                controlled_substance: false,
+                restricted_substance: false,
+                petroleum_base: false,
+                signal_word: SignalWord::None,
+                flash_point: Some(12.0),
+                boiling_point: Some(82.5),
+                nfpa_rating: None,
+                hmis_rating: None,
+                acid_base: None
+            },
+            components: vec![RecordComponent {
+                component: Component {
+                    id: ComponentId::generate(),
+                    chemical_name: "Propan-2-ol".to_string(),
+                    common_name: "Isopropanol".to_string(),
+                    cas_number: Some(CasNumber::from_str("67-63-0").unwrap()),
+                    substance_number: "".to_string(),
+                    trade_secret_number: "".to_string()
+                },
+                concentration: ConcentrationRange::new(60.0, 80.0).unwrap()
+            }],
+            hazards: test_fixtures::hazards(&["H225", "H319", "H336"]),
+            precautions: vec![Precaution::from_code("P210", &[]).unwrap(), Precaution::from_code("P233", &[]).unwrap()],
+            pictograms: vec![Pictogram::from(GhsPictogram::Flame)],
+            manufacturers: vec![RecordManufacturer {
+                manufacturer: Manufacturer {
+                    id: ManufacturerId::generate(),
+                    company_name: "Acme Chemical".to_string(),
+                    address: "1 Main St, Glassboro, NJ".to_string(),
+                    phone_number: "555-0100".to_string(),
+                    website: "".to_string()
+                },
+                manufacturer_number: "A-100".to_string()
+            }]
+        }
+    }
+
+    #[test]
+    fn test_label_from_record() {
+        let label = GhsLabel::from_record(&record()).unwrap();
+
+        assert_eq!(label.product_identifier, "Isopropyl Alcohol & Water");
+        assert_eq!(label.ingredients, vec!["Propan-2-ol (67-63-0)".to_string()]);
+        assert_eq!(label.signal_word, SignalWord::Danger);
+        assert_eq!(label.pictograms, vec![GhsPictogram::Flame, GhsPictogram::ExclamationMark]);
+        assert_eq!(label.hazard_statements.len(), 3);
+        assert!(label.hazard_statements[0].starts_with("H225 Highly flammable"));
+        assert!(label.precautionary_statements[0].starts_with("P210 Keep away from heat"));
+        assert_eq!(label.suppliers, vec!["Acme Chemical, 1 Main St, Glassboro, NJ, 555-0100".to_string()]);
+
+        let mut record = record();
+        record.pictograms.push(Pictogram {
+            picture_name: "Company Logo".to_string(),
+            pictogram_path: "logo.png".to_string(),
+            ..Pictogram::from(GhsPictogram::Flame)
+        });
+        assert!(GhsLabel::from_record(&record).is_err());
+    }
+
+    #[test]
+    fn test_label_from_record_supersedes_linked_pictograms() {
+        let mut toxic = record();
+        toxic.hazards = test_fixtures::hazards(&["H301"]);
+        toxic.pictograms = vec![Pictogram::from(GhsPictogram::ExclamationMark)];
+        assert_eq!(GhsLabel::from_record(&toxic).unwrap().pictograms, vec![GhsPictogram::SkullAndCrossbones]);
+
+        let mut linked_toxic = record();
+        linked_toxic.pictograms.push(Pictogram::from(GhsPictogram::SkullAndCrossbones));
+        assert_eq!(GhsLabel::from_record(&linked_toxic).unwrap().pictograms,
+            vec![GhsPictogram::Flame, GhsPictogram::SkullAndCrossbones]);
+    }
+
+    #[test]
+    fn test_label_size() {
+        assert_eq!(LabelSize::from_str("4x6"), Ok(LabelSize::FourBySix));
+        assert_eq!(LabelSize::from_str("2 X 4"), Ok(LabelSize::TwoByFour));
+        assert!(LabelSize::from_str("3x5").is_err());
+        assert_eq!(LabelSize::TwoByFour.to_string(), "2x4");
+        assert_eq!((LabelSize::FourBySix.width(), LabelSize::FourBySix.height()), (288.0, 432.0));
+    }
+
+    #[test]
+    fn test_wrap() {
+        let text = "Keep away from heat, hot surfaces, sparks, open flames and other ignition sources. No smoking.";
+        let lines = wrap(text, 100.0, 8.0, false);
+        assert!(lines.len() > 1);
+        assert!(lines.iter().all(|line| text_width(line, 8.0, false) <= 100.0));
+        assert_eq!(lines.join(" "), text);
+
+        let lines = wrap("Dimethylaminopropylmethacrylamide", 40.0, 10.0, true);
+        assert!(lines.len() > 1);
+        assert!(lines.iter().all(|line| text_width(line, 10.0, true) <= 40.0));
+        assert_eq!(lines.concat(), "Dimethylaminopropylmethacrylamide");
+    }
+
+    #[test]
+    fn test_label_svg() {
+        let label = GhsLabel::from_record(&record()).unwrap();
+        let svg = label.to_svg(LabelSize::FourBySix).unwrap();
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 288 432\" width=\"4in\" height=\"6in\">"));
+        assert!(svg.contains(">Isopropyl Alcohol &amp; Water</text>"));
+        assert!(svg.contains("font-weight=\"bold\">Danger</text>"));
+        assert!(svg.contains("<title>GHS02: Flame</title>"));
+        assert!(svg.contains("<title>GHS07: Exclamation mark</title>"));
+        assert_eq!(svg.matches("viewBox=\"0 0 200 200\"").count(), 2);
+        assert!(svg.contains("Supplier: Acme Chemical"));
+
+        let small = label.to_svg(LabelSize::TwoByFour).unwrap();
+        assert!(small.contains("width=\"4in\" height=\"2in\""));
+    }
+
+    #[test]
+    fn test_label_pdf() {
+        let label = GhsLabel::from_record(&record()).unwrap();
+        let pdf = label.to_pdf(LabelSize::TwoByFour).unwrap();
+        let text = String::from_utf8_lossy(&pdf).into_owned();
+
+        assert!(text.starts_with("%PDF-1.4\n"));
+        assert!(text.ends_with("%%EOF\n"));
+        assert!(text.contains("/MediaBox [0 0 288 144]"));
+        assert!(text.contains("(Danger) Tj"));
+
+        let xref: usize = text.rsplit("startxref\n").next().unwrap().lines().next().unwrap().parse().unwrap();
+        let table = String::from_utf8(pdf[xref..].to_vec()).unwrap();
+        assert!(table.starts_with("xref\n0 7\n"));
+        for (i, entry) in table.lines().skip(3).take(6).enumerate() {
+            let offset: usize = entry[..10].parse().unwrap();
+            assert!(pdf[offset..].starts_with(format!("{} 0 obj\n", i + 1).as_bytes()));
+        }
+    }
+
+    #[test]
+    fn test_label_too_long() {
+        let mut label = GhsLabel::from_record(&record()).unwrap();
+        let statement = label.precautionary_statements[0].clone();
+        label.precautionary_statements = vec![statement; 30];
+
+        assert!(label.to_svg(LabelSize::FourBySix).is_ok());
+        assert!(label.to_svg(LabelSize::TwoByFour).is_err());
+        assert!(label.to_pdf(LabelSize::TwoByFour).is_err());
+    }
+}
@@ -1,8 +1,139 @@
+extern crate chrono;
+extern crate csv;
 extern crate database_lib;
+#[macro_use]
+extern crate chemicals_derive;
+#[cfg(test)]
+extern crate proptest;
+#[cfg(feature = "sqlite")]
+extern crate rusqlite;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "serde")]
+extern crate serde_json;
+#[cfg(all(test, feature = "sqlite"))]
+extern crate tempfile;
+extern crate uuid;
+
+mod acid_base;
+mod cas_number;
 mod chemical;
+mod chemical_component;
+mod chemical_hazard;
 mod chemical_inventory;
+mod chemical_pictogram;
+mod chemical_precaution;
+mod chemical_record;
 mod component;
+mod container_type;
+mod csv_export;
+mod csv_import;
+mod date;
+mod entry;
+mod error;
+mod field_value;
+mod fire_code;
+mod ghs_pictogram;
 mod hazard;
+mod hazard_catalog;
+mod hazard_rating;
+mod id;
+#[cfg(feature = "serde")]
+mod json;
+mod label;
 mod manufacturer;
+mod manufacturer_chemical;
 mod pictogram;
-mod precaution;
\ No newline at end of file
+mod precaution;
+mod precaution_catalog;
+mod quantity;
+#[cfg(test)]
+mod round_trip;
+mod segregation;
+mod shelf_life;
+mod signal_word;
+mod sql_schema;
+#[cfg(feature = "sqlite")]
+mod sqlite_store;
+mod state_of_matter;
+mod storage_location;
+#[cfg(test)]
+mod test_fixtures;
+
+pub use acid_base::AcidBase;
+pub use cas_number::CasNumber;
+pub use chemical::{Chemical, ChemicalFields};
+pub use chemical_component::{ChemicalComponent, ChemicalComponentFields, ConcentrationRange};
+pub use chemical_hazard::{ChemicalHazard, ChemicalHazardFields};
+pub use chemical_inventory::{validate_inventory, ChemicalInventory, ChemicalInventoryFields};
+pub use chemical_pictogram::{ChemicalPictogram, ChemicalPictogramFields};
+pub use chemical_precaution::{ChemicalPrecaution, ChemicalPrecautionFields};
+pub use chemical_record::{ChemicalRecord, ChemicalTables, RecordComponent, RecordManufacturer, RecordSource};
+pub use component::{Component, ComponentFields};
+pub use container_type::{ContainerType, CONTAINER_TYPES};
+pub use csv_export::{export_csv, export_spreadsheet_csv};
+pub use csv_import::{import_csv, CsvImport, RowError};
+pub use entry::{ChemicalsEntry, Column, ColumnType, Reference};
+pub use error::ChemicalsError;
+pub use fire_code::{maq_report, ControlArea, FireCodeCategory, MaqLimit, MaqReport, MaqTable, MaqUsage};
+pub use ghs_pictogram::{required_pictograms, GhsPictogram, GHS_PICTOGRAMS};
+pub use hazard::{Hazard, HazardFields};
+pub use hazard_catalog::{hazard_statement, resolve_hazard_statement, HazardClass, HazardStatement, HAZARD_STATEMENTS};
+pub use hazard_rating::{door_placard, suggest_hmis_rating, suggest_nfpa_rating, DoorPlacard, HmisRating, NfpaRating, NfpaSpecial};
+pub use id::{ChemicalComponentId, ChemicalHazardId, ChemicalId, ChemicalPictogramId, ChemicalPrecautionId, ComponentId,
+    HazardId, InventoryId, LocationId, ManufacturerChemicalId, ManufacturerId, PictogramId, PrecautionId};
+#[cfg(feature = "serde")]
+pub use json::{create_from_json, json_schema, FieldSchema};
+pub use label::{GhsLabel, LabelSize};
+pub use manufacturer::{Manufacturer, ManufacturerFields};
+pub use manufacturer_chemical::{ManufacturerChemical, ManufacturerChemicalFields};
+pub use pictogram::{Pictogram, PictogramFields};
+pub use precaution::{Precaution, PrecautionFields};
+pub use precaution_catalog::{placeholders, precautionary_statement, render_precautionary_statement, Placeholder,
+    PrecautionaryStatement, PRECAUTIONARY_COMBINATIONS, PRECAUTIONARY_STATEMENTS};
+pub use quantity::{total_on_hand, Dimension, Quantity, Unit};
+pub use segregation::{check_segregation, incompatible, storage_group, storage_groups, SegregationConflict, StorageGroup,
+    SEGREGATION_MATRIX};
+pub use shelf_life::{expiration_date, LifeSpan};
+pub use signal_word::SignalWord;
+pub use sql_schema::{create_schema, create_table, SqlDialect};
+#[cfg(feature = "sqlite")]
+pub use sqlite_store::{SqliteStore, SqliteTable};
+pub use state_of_matter::StateOfMatter;
+pub use storage_location::{enclosing_cabinet, inventory_in, inventory_in_room, is_within, location_path, validate_locations, CabinetType,
+    LocationKind, StorageLocation, StorageLocationFields};
+
+/// Glob-importable set of the entity types together with the
+/// `database_lib` traits needed to build and read them.
+pub mod prelude {
+    pub use database_lib::interface::{Entry, FieldName, Value};
+
+    pub use acid_base::AcidBase;
+    pub use cas_number::CasNumber;
+    pub use chemical::{Chemical, ChemicalFields};
+    pub use chemical_component::{ChemicalComponent, ChemicalComponentFields, ConcentrationRange};
+    pub use chemical_hazard::{ChemicalHazard, ChemicalHazardFields};
+    pub use chemical_inventory::{ChemicalInventory, ChemicalInventoryFields};
+    pub use chemical_pictogram::{ChemicalPictogram, ChemicalPictogramFields};
+    pub use chemical_precaution::{ChemicalPrecaution, ChemicalPrecautionFields};
+    pub use chemical_record::{ChemicalRecord, ChemicalTables, RecordSource};
+    pub use component::{Component, ComponentFields};
+    pub use container_type::ContainerType;
+    pub use entry::ChemicalsEntry;
+    pub use error::ChemicalsError;
+    pub use ghs_pictogram::GhsPictogram;
+    pub use hazard::{Hazard, HazardFields};
+    pub use hazard_rating::{HmisRating, NfpaRating};
+    pub use id::{ChemicalComponentId, ChemicalHazardId, ChemicalId, ChemicalPictogramId, ChemicalPrecautionId, ComponentId,
+        HazardId, InventoryId, LocationId, ManufacturerChemicalId, ManufacturerId, PictogramId, PrecautionId};
+    pub use manufacturer::{Manufacturer, ManufacturerFields};
+    pub use manufacturer_chemical::{ManufacturerChemical, ManufacturerChemicalFields};
+    pub use pictogram::{Pictogram, PictogramFields};
+    pub use precaution::{Precaution, PrecautionFields};
+    pub use quantity::{Quantity, Unit};
+    pub use segregation::StorageGroup;
+    pub use shelf_life::LifeSpan;
+    pub use signal_word::SignalWord;
+    pub use state_of_matter::StateOfMatter;
+    pub use storage_location::{CabinetType, LocationKind, StorageLocation, StorageLocationFields};
+}
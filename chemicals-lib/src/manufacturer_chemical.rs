@@ -1,71 +1,21 @@
-use std::fmt::Display;
-use std::fmt;
-use std::str::FromStr;
+use chemical::Chemical;
+use id::{ChemicalId, ManufacturerChemicalId, ManufacturerId};
+use manufacturer::Manufacturer;
 
-use database_lib::interface::Entry;
-use database_lib::interface::Value;
-use database_lib::interface::FieldName;
-
-#[derive(Debug, Clone)]
+/// Links a chemical to a manufacturer that sells it, under that
+/// manufacturer's own product number.
+#[derive(PartialEq, Debug, Clone, Entry)]
 pub struct ManufacturerChemical {
+    #[field(name = "ID", primary_key)]
+    pub id: ManufacturerChemicalId,
+    #[field(name = "Manufacturer ID", references = "Manufacturer")]
+    pub manufacturer_id: ManufacturerId,
+    #[field(name = "Chemical ID", references = "Chemical")]
+    pub chemical_id: ChemicalId,
+    #[field(name = "Manufacturer Number")]
     pub manufacturer_number: String
 }
 
-#[derive(PartialEq, Clone, Copy, Debug)]
-pub enum ManufacturerChemicalFields {
-    ManufacturerNumber
-}
-
-impl FieldName for ManufacturerChemicalFields {}
-
-impl Display for ManufacturerChemicalFields {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            ManufacturerChemicalFields::ManufacturerNumber => write!(f, "Manufacturer Number")
-        }
-    }
-}
-
-impl FromStr for ManufacturerChemicalFields {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "Manufacturer Number" => Ok(ManufacturerChemicalFields::ManufacturerNumber),
-            _=> Err("Field does not exist".to_string()),
-        }
-    }
-}
-
-impl Entry for ManufacturerChemical {
-
-    type FieldNames = ManufacturerChemicalFields;
-
-    fn from_fields(values: &[Value]) -> Result<Self, String> {
-        if let Value::String(ref manufacturer_number) = values[0] {
-            Ok(ManufacturerChemical {
-                manufacturer_number: manufacturer_number.clone()
-            })
-        } else {
-            Err("Incorrect type manufacturer number. Should be String".to_string())
-        }
-    }
-
-    fn get_field_names() -> Vec<Self::FieldNames> {
-        vec![ManufacturerChemicalFields::ManufacturerNumber]
-    }
-
-    fn get_fields(&self) -> Vec<Value> {
-        vec![Value::String(self.manufacturer_number.clone())]
-    }
-
-    fn get_field(&self, field_name: ManufacturerChemicalFields) -> Option<Value> {
-        match field_name {
-            ManufacturerChemicalFields::ManufacturerNumber => Some(Value::String(self.manufacturer_number.clone()))
-        }
-    }
-}
-
 #[cfg(test)]
 mod manufacturer_chemical_tests {
 
@@ -74,23 +24,36 @@ mod manufacturer_chemical_tests {
     use manufacturer_chemical::ManufacturerChemicalFields;
     use manufacturer_chemical::ManufacturerChemical;
 
+    use id::{ChemicalId, ManufacturerChemicalId, ManufacturerId};
+
     use database_lib::interface::Entry;
     use database_lib::interface::Value;
 
     #[test]
     fn test_manufacturerchemicalfields_from_str() {
+        let manufacturerchemical_field = ManufacturerChemicalFields::from_str("Manufacturer ID");
+        assert_eq!(manufacturerchemical_field, Ok(ManufacturerChemicalFields::ManufacturerId));
+
+        let manufacturerchemical_field = ManufacturerChemicalFields::from_str("Chemical ID");
+        assert_eq!(manufacturerchemical_field, Ok(ManufacturerChemicalFields::ChemicalId));
+
         let manufacturerchemical_field = ManufacturerChemicalFields::from_str("Manufacturer Number");
         assert_eq!(manufacturerchemical_field, Ok(ManufacturerChemicalFields::ManufacturerNumber));
     }
 
     #[test]
-    fn test_manufacturerchemicalfields_from_fields() {
+    fn test_manufacturerchemical_from_fields() {
         let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("7c1d9e2f-4a3b-4c5d-8e6f-9a0b1c2d3e4f".to_string()),
+            Value::String("0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10".to_string()),
             Value::String("916225746".to_string())
         ];
 
         let manufacturer_chemical = ManufacturerChemical::from_fields(&fields).unwrap();
 
+        assert_eq!(manufacturer_chemical.manufacturer_id, ManufacturerId::from_str("7c1d9e2f-4a3b-4c5d-8e6f-9a0b1c2d3e4f").unwrap());
+        assert_eq!(manufacturer_chemical.chemical_id, ChemicalId::from_str("0b0e3f4a-8a51-4c4e-9d4e-2f6f0c1a7b10").unwrap());
         assert_eq!(manufacturer_chemical.manufacturer_number, "916225746".to_string());
     }
 
@@ -98,24 +61,34 @@ mod manufacturer_chemical_tests {
     fn test_manufacturerchemical_get_field_names() {
         let field_names = ManufacturerChemical::get_field_names();
 
-        assert_eq!(field_names[0], ManufacturerChemicalFields::ManufacturerNumber);
+        assert_eq!(field_names[1], ManufacturerChemicalFields::ManufacturerId);
+        assert_eq!(field_names[2], ManufacturerChemicalFields::ChemicalId);
+        assert_eq!(field_names[3], ManufacturerChemicalFields::ManufacturerNumber);
     }
 
     #[test]
     fn test_manufacturerchemical_get_fields() {
         let manufacturer_chemical = ManufacturerChemical {
+            id: ManufacturerChemicalId::generate(),
+            manufacturer_id: ManufacturerId::generate(),
+            chemical_id: ChemicalId::generate(),
             manufacturer_number: "444".to_string()
         };
 
         let fields = manufacturer_chemical.get_fields();
 
-        assert_eq!(fields[0], Value::String("444".to_string()));
-        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[1], Value::String(manufacturer_chemical.manufacturer_id.to_string()));
+        assert_eq!(fields[2], Value::String(manufacturer_chemical.chemical_id.to_string()));
+        assert_eq!(fields[3], Value::String("444".to_string()));
+        assert_eq!(fields.len(), 4);
     }
 
     #[test]
     fn test_manufacturerchemical_get_field() {
         let manufacturer_chemical = ManufacturerChemical {
+            id: ManufacturerChemicalId::generate(),
+            manufacturer_id: ManufacturerId::generate(),
+            chemical_id: ChemicalId::generate(),
             manufacturer_number: "1234".to_string()
         };
 
@@ -123,4 +96,4 @@ mod manufacturer_chemical_tests {
 
         assert_eq!(manufacturer_number, Some(Value::String("1234".to_string())));
     }
-}
\ No newline at end of file
+}
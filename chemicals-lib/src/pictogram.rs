@@ -1,79 +1,36 @@
-use std::fmt::Display;
-use std::fmt;
 use std::str::FromStr;
 
-use database_lib::interface::Entry;
-use database_lib::interface::Value;
-use database_lib::interface::FieldName;
+use ghs_pictogram::GhsPictogram;
+use id::PictogramId;
 
-#[derive(Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Entry)]
 pub struct Pictogram {
+    #[field(name = "ID", primary_key)]
+    pub id: PictogramId,
+    #[field(name = "Picture Name")]
     pub picture_name: String,
+    #[field(name = "Pictogram Path")]
     pub pictogram_path: String
 }
 
-#[derive(PartialEq, Clone, Copy, Debug)]
-pub enum PictogramFields {
-    PictureName,
-    PictogramPath
-}
-
-impl FieldName for PictogramFields {}
-
-impl Display for PictogramFields {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            PictogramFields::PictureName => write!(f, "PictureName"),
-            PictogramFields::PictogramPath => write!(f, "Pictogram Path")
-        }
+impl Pictogram {
+    /// Maps a stored row onto its GHS pictogram, by picture name or else by
+    /// the file name in its path (e.g. `images/ghs02.png` or `flame.svg`).
+    pub fn ghs_pictogram(&self) -> Result<GhsPictogram, String> {
+        GhsPictogram::from_str(&self.picture_name).or_else(|_| {
+            let file_name = self.pictogram_path.rsplit(['/', '\\']).next().unwrap_or("");
+            let stem = file_name.split('.').next().unwrap_or("");
+            GhsPictogram::from_str(stem)
+        }).map_err(|_| format!("Pictogram {} does not match a GHS pictogram", self.picture_name))
     }
 }
 
-impl FromStr for PictogramFields {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "Picture Name" => Ok(PictogramFields::PictureName),
-            "Pictogram Path" => Ok(PictogramFields::PictogramPath),
-            _=> Err("Field does not exist".to_string()),
-        }
-    }
-}
-
-impl Entry for Pictogram {
-
-    type FieldNames = PictogramFields;
-
-    fn from_fields(values: &[Value]) -> Result<Self, String> {
-        if let Value::String(ref picture_name) = values[0] {
-            if let Value::String(ref pictogram_path) = values[1] {
-                Ok(Pictogram {
-                    picture_name: picture_name.clone(),
-                    pictogram_path: pictogram_path.clone()
-                })
-            } else {
-                Err("Incorrect type for pictogram path. Should be String".to_string())
-            }
-        } else {
-            Err("Incorrect type for picture name. Should be String".to_string())
-        }
-    }
-
-    fn get_field_names() -> Vec<Self::FieldNames> {
-        vec![PictogramFields::PictureName,
-            PictogramFields::PictogramPath]
-    }
-
-    fn get_fields(&self) -> Vec<Value> {
-        vec![Value::String(self.picture_name.clone()),
-            Value::String(self.pictogram_path.clone())]
-    }
-
-    fn get_field(&self, field_name: PictogramFields) -> Option<Value> {
-        match field_name {
-            PictogramFields::PictureName => Some(Value::String(self.picture_name.clone())),
-            PictogramFields::PictogramPath => Some(Value::String(self.pictogram_path.clone()))
+impl From<GhsPictogram> for Pictogram {
+    fn from(pictogram: GhsPictogram) -> Self {
+        Pictogram {
+            id: PictogramId::generate(),
+            picture_name: pictogram.name().to_string(),
+            pictogram_path: format!("{}.svg", pictogram.code())
         }
     }
 }
@@ -85,6 +42,9 @@ mod pictogram_test {
 
     use pictogram::PictogramFields;
     use pictogram::Pictogram;
+    use ghs_pictogram::GhsPictogram;
+
+    use id::PictogramId;
 
     use database_lib::interface::Entry;
     use database_lib::interface::Value;
@@ -101,6 +61,7 @@ mod pictogram_test {
     #[test]
     fn test_pictogram_from_fields() {
         let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
             Value::String("Environmental Hazard".to_string()),
             Value::String("9".to_string())
         ];
@@ -115,27 +76,29 @@ mod pictogram_test {
     fn test_pictogram_get_field_names() {
         let field_names = Pictogram::get_field_names();
 
-        assert_eq!(field_names[0], PictogramFields::PictureName);
-        assert_eq!(field_names[1], PictogramFields::PictogramPath);
+        assert_eq!(field_names[1], PictogramFields::PictureName);
+        assert_eq!(field_names[2], PictogramFields::PictogramPath);
     }
 
     #[test]
     fn test_pictogram_get_fields() {
         let pictogram = Pictogram {
+            id: PictogramId::generate(),
             picture_name: "Health Hazard".to_string(),
             pictogram_path: "8".to_string()
         };
 
         let fields = pictogram.get_fields();
 
-        assert_eq!(fields[0], Value::String("Health Hazard".to_string()));
-        assert_eq!(fields[1], Value::String("8".to_string()));
-        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[1], Value::String("Health Hazard".to_string()));
+        assert_eq!(fields[2], Value::String("8".to_string()));
+        assert_eq!(fields.len(), 3);
     }
 
     #[test]
     fn test_pictogram_get_field() {
         let pictogram = Pictogram {
+            id: PictogramId::generate(),
             picture_name: "Toxic".to_string(),
             pictogram_path: "6".to_string()
         };
@@ -144,4 +107,36 @@ mod pictogram_test {
 
         assert_eq!(picture_name, Some(Value::String("Toxic".to_string())));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_pictogram_ghs_pictogram() {
+        let by_name = Pictogram {
+            id: PictogramId::generate(),
+            picture_name: "Environmental Hazard".to_string(),
+            pictogram_path: "9".to_string()
+        };
+        assert_eq!(by_name.ghs_pictogram(), Ok(GhsPictogram::Environment));
+
+        let by_path = Pictogram {
+            id: PictogramId::generate(),
+            picture_name: "Fire".to_string(),
+            pictogram_path: "C:\\Pictograms\\ghs02.png".to_string()
+        };
+        assert_eq!(by_path.ghs_pictogram(), Ok(GhsPictogram::Flame));
+
+        let unknown = Pictogram {
+            id: PictogramId::generate(),
+            picture_name: "Smiley".to_string(),
+            pictogram_path: "smiley.png".to_string()
+        };
+        assert!(unknown.ghs_pictogram().is_err());
+    }
+
+    #[test]
+    fn test_pictogram_from_ghs_pictogram() {
+        let pictogram = Pictogram::from(GhsPictogram::Corrosion);
+
+        assert_eq!(pictogram.picture_name, "Corrosion".to_string());
+        assert_eq!(pictogram.ghs_pictogram(), Ok(GhsPictogram::Corrosion));
+    }
+}
@@ -1,68 +1,35 @@
-use std::fmt::Display;
-use std::fmt;
-use std::str::FromStr;
+use id::PrecautionId;
+use precaution_catalog::{normalize_precautionary_code, precautionary_statement, render_precautionary_statement};
 
-use database_lib::interface::Entry;
-use database_lib::interface::Value;
-use database_lib::interface::FieldName;
-
-#[derive(Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Entry)]
 pub struct Precaution {
+    #[field(name = "ID", primary_key)]
+    pub id: PrecautionId,
+    #[field(name = "Code", parse = "parse_precaution_code", index)]
+    pub code: Option<String>,
+    #[field(name = "Statement")]
     pub statement: String
 }
 
-#[derive(PartialEq, Clone, Copy, Debug)]
-pub enum PrecautionFields {
-    Statement
-}
-
-impl FieldName for PrecautionFields {}
-
-impl Display for PrecautionFields {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            PrecautionFields::Statement => write!(f, "Statement")
-        }
+/// A blank code is a free-text precaution; any other code must be in the catalog.
+fn parse_precaution_code(code: &str) -> Result<Option<String>, String> {
+    if code.trim().is_empty() {
+        Ok(None)
+    } else {
+        precautionary_statement(code)?;
+        Ok(Some(normalize_precautionary_code(code)))
     }
 }
 
-impl FromStr for PrecautionFields {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "Statement" => Ok(PrecautionFields::Statement),
-            _=> Err("Field does not exist".to_string()),
-        }
-    }
-}
-
-impl Entry for Precaution {
-
-    type FieldNames = PrecautionFields;
-
-    fn from_fields(values: &[Value]) -> Result<Self, String> {
-        if let Value::String(ref statement) = values[0] {
-            Ok(Precaution {
-                statement: statement.clone()
-            })
-        } else {
-            Err("Incorrect type statement. Should be String".to_string())
-        }
-    }
-
-    fn get_field_names() -> Vec<Self::FieldNames> {
-        vec![PrecautionFields::Statement]
-    }
-
-    fn get_fields(&self) -> Vec<Value> {
-        vec![Value::String(self.statement.clone())]
-    }
-
-    fn get_field(&self, field_name: PrecautionFields) -> Option<Value> {
-        match field_name {
-            PrecautionFields::Statement => Some(Value::String(self.statement.clone()))
-        }
+impl Precaution {
+    /// Builds a precaution from a single or combined code using the built-in
+    /// catalog, filling in the statement's blanks in order with `fill_ins`.
+    pub fn from_code(code: &str, fill_ins: &[&str]) -> Result<Precaution, String> {
+        Ok(Precaution {
+            id: PrecautionId::generate(),
+            code: Some(normalize_precautionary_code(code)),
+            statement: render_precautionary_statement(code, fill_ins)?
+        })
     }
 }
 
@@ -74,11 +41,16 @@ mod precuation_tests {
     use precaution::PrecautionFields;
     use precaution::Precaution;
 
+    use id::PrecautionId;
+
     use database_lib::interface::Entry;
     use database_lib::interface::Value;
 
     #[test]
     fn test_precautionfields_from_str() {
+        let precaution_field = PrecautionFields::from_str("Code");
+        assert_eq!(precaution_field, Ok(PrecautionFields::Code));
+
         let precaution_field = PrecautionFields::from_str("Statement");
         assert_eq!(precaution_field, Ok(PrecautionFields::Statement));
     }
@@ -86,36 +58,46 @@ mod precuation_tests {
     #[test]
     fn test_precaution_from_fields() {
         let fields = [
-            Value::String("916225746".to_string())
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("p403 + p233".to_string()),
+            Value::String("Store in a well-ventilated place. Keep container tightly closed.".to_string())
         ];
 
         let precaution = Precaution::from_fields(&fields).unwrap();
 
-        assert_eq!(precaution.statement, "916225746".to_string());
+        assert_eq!(precaution.code, Some("P403+P233".to_string()));
+        assert_eq!(precaution.statement, "Store in a well-ventilated place. Keep container tightly closed.".to_string());
     }
 
     #[test]
     fn test_precaution_get_field_names() {
         let field_names = Precaution::get_field_names();
 
-        assert_eq!(field_names[0], PrecautionFields::Statement);
+        assert_eq!(field_names[1], PrecautionFields::Code);
+        assert_eq!(field_names[2], PrecautionFields::Statement);
+        assert_eq!(field_names.len(), 3);
     }
 
     #[test]
     fn test_precaution_get_fields() {
         let precaution = Precaution {
-            statement: "444".to_string()
+            id: PrecautionId::generate(),
+            code: Some("P235".to_string()),
+            statement: "Keep cool.".to_string()
         };
 
         let fields = precaution.get_fields();
 
-        assert_eq!(fields[0], Value::String("444".to_string()));
-        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[1], Value::String("P235".to_string()));
+        assert_eq!(fields[2], Value::String("Keep cool.".to_string()));
+        assert_eq!(fields.len(), 3);
     }
 
     #[test]
     fn test_precaution_get_field() {
         let precaution = Precaution {
+            id: PrecautionId::generate(),
+            code: None,
             statement: "1234".to_string()
         };
 
@@ -123,4 +105,24 @@ mod precuation_tests {
 
         assert_eq!(statement, Some(Value::String("1234".to_string())));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_precaution_from_fields_rejects_invalid_combination() {
+        let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String("P301+P233".to_string()),
+            Value::String("".to_string())
+        ];
+
+        assert!(Precaution::from_fields(&fields).is_err());
+    }
+
+    #[test]
+    fn test_precaution_from_code() {
+        let precaution = Precaution::from_code("P370+P378", &["water spray"]).unwrap();
+
+        assert_eq!(precaution.code, Some("P370+P378".to_string()));
+        assert_eq!(precaution.statement, "In case of fire: Use water spray to extinguish.".to_string());
+        assert!(Precaution::from_code("P370+P378", &[]).is_err());
+    }
+}
@@ -0,0 +1,342 @@
+/// One entry of the GHS precautionary statement catalog. Blanks the label
+/// author has to fill in are written in square brackets, e.g. `[appropriate media]`.
+#[derive(PartialEq, Debug)]
+pub struct PrecautionaryStatement {
+    pub code: &'static str,
+    pub statement: &'static str
+}
+
+const fn entry(code: &'static str, statement: &'static str) -> PrecautionaryStatement {
+    PrecautionaryStatement { code, statement }
+}
+
+/// GHS precautionary statements P101 through P502.
+pub static PRECAUTIONARY_STATEMENTS: &[PrecautionaryStatement] = &[
+    entry("P101", "If medical advice is needed, have product container or label at hand."),
+    entry("P102", "Keep out of reach of children."),
+    entry("P103", "Read carefully and follow all instructions."),
+    entry("P201", "Obtain special instructions before use."),
+    entry("P202", "Do not handle until all safety precautions have been read and understood."),
+    entry("P203", "Obtain, read and follow all safety instructions before use."),
+    entry("P210", "Keep away from heat, hot surfaces, sparks, open flames and other ignition sources. No smoking."),
+    entry("P211", "Do not spray on an open flame or other ignition source."),
+    entry("P212", "Avoid heating under confinement or reduction of the desensitizing agent."),
+    entry("P220", "Keep away from clothing and other combustible materials."),
+    entry("P222", "Do not allow contact with air."),
+    entry("P223", "Do not allow contact with water."),
+    entry("P230", "Keep wetted with [appropriate material]."),
+    entry("P231", "Handle and store contents under [inert gas]."),
+    entry("P232", "Protect from moisture."),
+    entry("P233", "Keep container tightly closed."),
+    entry("P234", "Keep only in original packaging."),
+    entry("P235", "Keep cool."),
+    entry("P240", "Ground and bond container and receiving equipment."),
+    entry("P241", "Use explosion-proof [electrical/ventilating/lighting] equipment."),
+    entry("P242", "Use non-sparking tools."),
+    entry("P243", "Take action to prevent static discharges."),
+    entry("P244", "Keep valves and fittings free from oil and grease."),
+    entry("P250", "Do not subject to [grinding/shock/friction]."),
+    entry("P251", "Do not pierce or burn, even after use."),
+    entry("P260", "Do not breathe [dust/fume/gas/mist/vapors/spray]."),
+    entry("P261", "Avoid breathing [dust/fume/gas/mist/vapors/spray]."),
+    entry("P262", "Do not get in eyes, on skin, or on clothing."),
+    entry("P263", "Avoid contact during pregnancy and while nursing."),
+    entry("P264", "Wash [hands] thoroughly after handling."),
+    entry("P265", "Do not touch eyes."),
+    entry("P270", "Do not eat, drink or smoke when using this product."),
+    entry("P271", "Use only outdoors or in a well-ventilated area."),
+    entry("P272", "Contaminated work clothing should not be allowed out of the workplace."),
+    entry("P273", "Avoid release to the environment."),
+    entry("P280", "Wear [protective gloves/protective clothing/eye protection/face protection]."),
+    entry("P282", "Wear cold insulating gloves and either face shield or eye protection."),
+    entry("P283", "Wear fire resistant or flame retardant clothing."),
+    entry("P284", "In case of inadequate ventilation wear respiratory protection."),
+    entry("P301", "IF SWALLOWED:"),
+    entry("P302", "IF ON SKIN:"),
+    entry("P303", "IF ON SKIN (or hair):"),
+    entry("P304", "IF INHALED:"),
+    entry("P305", "IF IN EYES:"),
+    entry("P306", "IF ON CLOTHING:"),
+    entry("P308", "IF exposed or concerned:"),
+    entry("P310", "Immediately call a POISON CENTER/doctor."),
+    entry("P311", "Call a POISON CENTER/doctor."),
+    entry("P312", "Call a POISON CENTER/doctor if you feel unwell."),
+    entry("P313", "Get medical advice/attention."),
+    entry("P314", "Get medical advice/attention if you feel unwell."),
+    entry("P315", "Get immediate medical advice/attention."),
+    entry("P316", "Get emergency medical help immediately."),
+    entry("P317", "Get medical help."),
+    entry("P318", "If exposed or concerned, get medical advice."),
+    entry("P319", "Get medical help if you feel unwell."),
+    entry("P320", "Specific treatment is urgent (see [supplemental first aid instruction] on this label)."),
+    entry("P321", "Specific treatment (see [supplemental first aid instruction] on this label)."),
+    entry("P330", "Rinse mouth."),
+    entry("P331", "Do NOT induce vomiting."),
+    entry("P332", "If skin irritation occurs:"),
+    entry("P333", "If skin irritation or rash occurs:"),
+    entry("P334", "Immerse in cool water or wrap in wet bandages."),
+    entry("P335", "Brush off loose particles from skin."),
+    entry("P336", "Thaw frosted parts with lukewarm water. Do not rub affected area."),
+    entry("P337", "If eye irritation persists:"),
+    entry("P338", "Remove contact lenses, if present and easy to do. Continue rinsing."),
+    entry("P340", "Remove person to fresh air and keep comfortable for breathing."),
+    entry("P342", "If experiencing respiratory symptoms:"),
+    entry("P351", "Rinse cautiously with water for several minutes."),
+    entry("P352", "Wash with plenty of [water]."),
+    entry("P353", "Rinse skin with water or shower."),
+    entry("P354", "Immediately rinse with water for several minutes."),
+    entry("P360", "Rinse immediately contaminated clothing and skin with plenty of water before removing clothes."),
+    entry("P361", "Take off immediately all contaminated clothing."),
+    entry("P362", "Take off contaminated clothing."),
+    entry("P363", "Wash contaminated clothing before reuse."),
+    entry("P364", "And wash it before reuse."),
+    entry("P370", "In case of fire:"),
+    entry("P371", "In case of major fire and large quantities:"),
+    entry("P372", "Explosion risk."),
+    entry("P373", "DO NOT fight fire when fire reaches explosives."),
+    entry("P375", "Fight fire remotely due to the risk of explosion."),
+    entry("P376", "Stop leak if safe to do so."),
+    entry("P377", "Leaking gas fire: Do not extinguish, unless leak can be stopped safely."),
+    entry("P378", "Use [appropriate media] to extinguish."),
+    entry("P380", "Evacuate area."),
+    entry("P381", "In case of leakage, eliminate all ignition sources."),
+    entry("P390", "Absorb spillage to prevent material damage."),
+    entry("P391", "Collect spillage."),
+    entry("P401", "Store in accordance with [local/regional/national/international regulations]."),
+    entry("P402", "Store in a dry place."),
+    entry("P403", "Store in a well-ventilated place."),
+    entry("P404", "Store in a closed container."),
+    entry("P405", "Store locked up."),
+    entry("P406", "Store in a corrosion resistant container with a resistant inner liner."),
+    entry("P407", "Maintain air gap between stacks or pallets."),
+    entry("P410", "Protect from sunlight."),
+    entry("P411", "Store at temperatures not exceeding [temperature]."),
+    entry("P412", "Do not expose to temperatures exceeding 50 °C/122 °F."),
+    entry("P413", "Store bulk masses greater than [mass] at temperatures not exceeding [temperature]."),
+    entry("P420", "Store separately."),
+    entry("P501", "Dispose of contents/container to [disposal facility]."),
+    entry("P502", "Refer to manufacturer or supplier for information on recovery or recycling.")
+];
+
+/// The combined statements GHS allows, e.g. `P301+P310`.
+pub static PRECAUTIONARY_COMBINATIONS: &[&str] = &[
+    "P231+P232",
+    "P264+P265",
+    "P301+P310",
+    "P301+P312",
+    "P301+P316",
+    "P301+P317",
+    "P301+P330+P331",
+    "P302+P334",
+    "P302+P335+P334",
+    "P302+P352",
+    "P302+P361+P354",
+    "P303+P361+P353",
+    "P304+P312",
+    "P304+P317",
+    "P304+P340",
+    "P305+P351+P338",
+    "P305+P354+P338",
+    "P306+P360",
+    "P308+P311",
+    "P308+P313",
+    "P308+P316",
+    "P332+P313",
+    "P332+P317",
+    "P333+P313",
+    "P333+P317",
+    "P336+P315",
+    "P336+P317",
+    "P337+P313",
+    "P337+P317",
+    "P342+P311",
+    "P342+P316",
+    "P361+P364",
+    "P362+P364",
+    "P370+P372+P380+P373",
+    "P370+P376",
+    "P370+P378",
+    "P370+P380+P375",
+    "P370+P380+P375+P378",
+    "P371+P380+P375",
+    "P402+P404",
+    "P403+P233",
+    "P403+P235",
+    "P410+P403",
+    "P410+P412"
+];
+
+/// Normalizes a single or combined code, so `p301 + p310` becomes `P301+P310`.
+pub fn normalize_precautionary_code(code: &str) -> String {
+    code.split('+')
+        .map(|part| part.trim().to_uppercase())
+        .collect::<Vec<String>>()
+        .join("+")
+}
+
+/// The unfilled text of a single or combined precautionary statement.
+/// Combined statements must be one of `PRECAUTIONARY_COMBINATIONS`.
+pub fn precautionary_statement(code: &str) -> Result<String, String> {
+    let code = normalize_precautionary_code(code);
+
+    let statements = code.split('+')
+        .map(|part| PRECAUTIONARY_STATEMENTS.iter()
+            .find(|entry| entry.code == part)
+            .map(|entry| entry.statement)
+            .ok_or_else(|| format!("Unknown precautionary statement code: {}", part)))
+        .collect::<Result<Vec<&str>, String>>()?;
+
+    if statements.len() > 1 && !PRECAUTIONARY_COMBINATIONS.contains(&code.as_str()) {
+        return Err(format!("Invalid precautionary statement combination: {}", code));
+    }
+
+    Ok(statements.join(" "))
+}
+
+/// A blank in a precautionary statement. A blank whose text lists options
+/// separated by `/`, such as `[protective gloves/eye protection]`, is a
+/// choice the label author picks from; any other blank is free text.
+#[derive(PartialEq, Debug)]
+pub enum Placeholder<'a> {
+    FreeText(&'a str),
+    Choice(Vec<&'a str>)
+}
+
+impl<'a> Placeholder<'a> {
+    fn parse(blank: &'a str) -> Placeholder<'a> {
+        if blank.contains('/') {
+            Placeholder::Choice(blank.split('/').map(|option| option.trim()).collect())
+        } else {
+            Placeholder::FreeText(blank)
+        }
+    }
+
+    /// The text that replaces the blank. A choice takes one or more of its
+    /// options, separated by `/`; free text takes anything but an empty string.
+    pub fn fill(&self, fill_in: &str) -> Result<String, String> {
+        match self {
+            Placeholder::FreeText(blank) => {
+                if fill_in.trim().is_empty() {
+                    Err(format!("[{}] cannot be left blank", blank))
+                } else {
+                    Ok(fill_in.trim().to_string())
+                }
+            },
+            Placeholder::Choice(options) => {
+                let chosen = fill_in.split('/')
+                    .map(|choice| options.iter()
+                        .find(|option| option.eq_ignore_ascii_case(choice.trim()))
+                        .cloned()
+                        .ok_or_else(|| format!("{} is not one of [{}]", choice.trim(), options.join("/"))))
+                    .collect::<Result<Vec<&str>, String>>()?;
+                Ok(chosen.join("/"))
+            }
+        }
+    }
+}
+
+/// The blanks in a statement, in the order they appear.
+pub fn placeholders(statement: &str) -> Vec<Placeholder<'_>> {
+    let mut placeholders = Vec::new();
+    let mut rest = statement;
+    while let Some(start) = rest.find('[') {
+        match rest[start..].find(']') {
+            Some(end) => {
+                placeholders.push(Placeholder::parse(&rest[start + 1..start + end]));
+                rest = &rest[start + end + 1..];
+            },
+            None => break
+        }
+    }
+    placeholders
+}
+
+/// The full text of a statement with its blanks filled in, in order, by
+/// `fill_ins`. Each fill-in for a choice names one or more of its options,
+/// e.g. `"protective gloves/eye protection"` for P280.
+pub fn render_precautionary_statement(code: &str, fill_ins: &[&str]) -> Result<String, String> {
+    let statement = precautionary_statement(code)?;
+    let blanks = placeholders(&statement);
+    if blanks.len() != fill_ins.len() {
+        return Err(format!("{} needs {} fill-ins but {} were given",
+            normalize_precautionary_code(code), blanks.len(), fill_ins.len()));
+    }
+
+    let mut rendered = String::new();
+    let mut rest = statement.as_str();
+    for (blank, fill_in) in blanks.iter().zip(fill_ins) {
+        let start = rest.find('[').unwrap_or(rest.len());
+        let end = rest[start..].find(']').map(|end| start + end + 1).unwrap_or(rest.len());
+        rendered.push_str(&rest[..start]);
+        rendered.push_str(&blank.fill(fill_in)?);
+        rest = &rest[end..];
+    }
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+#[cfg(test)]
+mod precaution_catalog_tests {
+
+    use precaution_catalog::{placeholders, precautionary_statement, render_precautionary_statement, Placeholder,
+        PRECAUTIONARY_COMBINATIONS, PRECAUTIONARY_STATEMENTS};
+
+    #[test]
+    fn test_precautionary_statement_lookup() {
+        assert_eq!(precautionary_statement("P233"), Ok("Keep container tightly closed.".to_string()));
+        assert_eq!(precautionary_statement("p301 + p310"),
+            Ok("IF SWALLOWED: Immediately call a POISON CENTER/doctor.".to_string()));
+        assert!(precautionary_statement("P999").is_err());
+    }
+
+    #[test]
+    fn test_precautionary_statement_rejects_invalid_combination() {
+        let error = precautionary_statement("P301+P233").unwrap_err();
+        assert!(error.contains("Invalid precautionary statement combination"));
+    }
+
+    #[test]
+    fn test_precautionary_combinations_are_catalogued() {
+        for combination in PRECAUTIONARY_COMBINATIONS.iter() {
+            assert!(precautionary_statement(combination).is_ok(), "{} is not catalogued", combination);
+        }
+    }
+
+    #[test]
+    fn test_precautionary_codes_are_unique() {
+        for (i, entry) in PRECAUTIONARY_STATEMENTS.iter().enumerate() {
+            assert!(PRECAUTIONARY_STATEMENTS[i + 1..].iter().all(|other| other.code != entry.code), "{} is duplicated", entry.code);
+        }
+    }
+
+    #[test]
+    fn test_placeholders() {
+        assert_eq!(placeholders("Use [appropriate media] to extinguish."), vec![Placeholder::FreeText("appropriate media")]);
+        assert_eq!(placeholders("Store bulk masses greater than [mass] at temperatures not exceeding [temperature]."),
+            vec![Placeholder::FreeText("mass"), Placeholder::FreeText("temperature")]);
+        assert_eq!(placeholders("Do not subject to [grinding/shock/friction]."),
+            vec![Placeholder::Choice(vec!["grinding", "shock", "friction"])]);
+        assert!(placeholders("Keep cool.").is_empty());
+    }
+
+    #[test]
+    fn test_render_precautionary_statement() {
+        assert_eq!(render_precautionary_statement("P370+P378", &["dry sand, dry chemical or alcohol-resistant foam"]),
+            Ok("In case of fire: Use dry sand, dry chemical or alcohol-resistant foam to extinguish.".to_string()));
+        assert_eq!(render_precautionary_statement("P235", &[]), Ok("Keep cool.".to_string()));
+        assert!(render_precautionary_statement("P378", &[]).is_err());
+        assert!(render_precautionary_statement("P235", &["too many"]).is_err());
+        assert!(render_precautionary_statement("P378", &[" "]).is_err());
+    }
+
+    #[test]
+    fn test_render_precautionary_statement_choice() {
+        assert_eq!(render_precautionary_statement("P280", &["protective gloves/eye protection"]),
+            Ok("Wear protective gloves/eye protection.".to_string()));
+        assert_eq!(render_precautionary_statement("P280", &["Face Protection"]),
+            Ok("Wear face protection.".to_string()));
+        assert!(render_precautionary_statement("P280", &["a hat"]).is_err());
+        assert!(render_precautionary_statement("P280", &["protective gloves/"]).is_err());
+        assert!(render_precautionary_statement("P280", &[]).is_err());
+    }
+}
@@ -0,0 +1,226 @@
+use std::fmt::Display;
+use std::fmt;
+use std::str::FromStr;
+
+use chemical_inventory::ChemicalInventory;
+use id::ChemicalId;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum Dimension {
+    Mass,
+    Volume,
+    Count
+}
+
+impl Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Dimension::Mass => write!(f, "mass"),
+            Dimension::Volume => write!(f, "volume"),
+            Dimension::Count => write!(f, "count")
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum Unit {
+    Milligram,
+    Gram,
+    Kilogram,
+    Ounce,
+    Pound,
+    Milliliter,
+    Liter,
+    FluidOunce,
+    Pint,
+    Quart,
+    Gallon,
+    Each
+}
+
+impl Unit {
+    pub fn dimension(&self) -> Dimension {
+        match self {
+            Unit::Milligram | Unit::Gram | Unit::Kilogram | Unit::Ounce | Unit::Pound => Dimension::Mass,
+            Unit::Milliliter | Unit::Liter | Unit::FluidOunce | Unit::Pint | Unit::Quart | Unit::Gallon => Dimension::Volume,
+            Unit::Each => Dimension::Count
+        }
+    }
+
+    /// How many grams, milliliters or items one of this unit is.
+    fn base_factor(&self) -> f64 {
+        match self {
+            Unit::Milligram => 0.001,
+            Unit::Gram => 1.0,
+            Unit::Kilogram => 1000.0,
+            Unit::Ounce => 28.349_523_125,
+            Unit::Pound => 453.592_37,
+            Unit::Milliliter => 1.0,
+            Unit::Liter => 1000.0,
+            Unit::FluidOunce => 29.573_529_562_5,
+            Unit::Pint => 473.176_473,
+            Unit::Quart => 946.352_946,
+            Unit::Gallon => 3_785.411_784,
+            Unit::Each => 1.0
+        }
+    }
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Unit::Milligram => write!(f, "mg"),
+            Unit::Gram => write!(f, "g"),
+            Unit::Kilogram => write!(f, "kg"),
+            Unit::Ounce => write!(f, "oz"),
+            Unit::Pound => write!(f, "lb"),
+            Unit::Milliliter => write!(f, "mL"),
+            Unit::Liter => write!(f, "L"),
+            Unit::FluidOunce => write!(f, "fl oz"),
+            Unit::Pint => write!(f, "pt"),
+            Unit::Quart => write!(f, "qt"),
+            Unit::Gallon => write!(f, "gal"),
+            Unit::Each => write!(f, "ea")
+        }
+    }
+}
+
+impl FromStr for Unit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "mg" | "milligram" | "milligrams" => Ok(Unit::Milligram),
+            "g" | "gram" | "grams" => Ok(Unit::Gram),
+            "kg" | "kilogram" | "kilograms" => Ok(Unit::Kilogram),
+            "oz" | "ounce" | "ounces" => Ok(Unit::Ounce),
+            "lb" | "lbs" | "pound" | "pounds" => Ok(Unit::Pound),
+            "ml" | "milliliter" | "milliliters" | "millilitre" | "millilitres" => Ok(Unit::Milliliter),
+            "l" | "liter" | "liters" | "litre" | "litres" => Ok(Unit::Liter),
+            "fl oz" | "fluid ounce" | "fluid ounces" => Ok(Unit::FluidOunce),
+            "pt" | "pint" | "pints" => Ok(Unit::Pint),
+            "qt" | "quart" | "quarts" => Ok(Unit::Quart),
+            "gal" | "gallon" | "gallons" => Ok(Unit::Gallon),
+            "ea" | "each" | "count" => Ok(Unit::Each),
+            _ => Err(format!("Unknown unit: {}", s))
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Quantity {
+    pub amount: f64,
+    pub unit: Unit
+}
+
+impl Quantity {
+    pub fn new(amount: f64, unit: Unit) -> Quantity {
+        Quantity { amount, unit }
+    }
+
+    pub fn convert_to(&self, unit: Unit) -> Result<Quantity, String> {
+        if self.unit.dimension() != unit.dimension() {
+            return Err(format!("Cannot convert {} of {} to {}, which measures {}",
+                self.unit.dimension(), self, unit, unit.dimension()));
+        }
+
+        Ok(Quantity::new(self.amount * self.unit.base_factor() / unit.base_factor(), unit))
+    }
+
+    /// Adds `other` to this quantity, keeping this quantity's unit.
+    pub fn checked_add(&self, other: &Quantity) -> Result<Quantity, String> {
+        let other = other.convert_to(self.unit)?;
+        Ok(Quantity::new(self.amount + other.amount, self.unit))
+    }
+}
+
+impl Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.amount, self.unit)
+    }
+}
+
+/// Sums what is left in every active container of `chemical_id`,
+/// normalized to `unit`. Containers of other chemicals are skipped.
+pub fn total_on_hand<'a, I>(chemical_id: ChemicalId, inventory: I, unit: Unit) -> Result<Quantity, String>
+    where I: IntoIterator<Item = &'a ChemicalInventory>
+{
+    inventory.into_iter()
+        .filter(|container| container.active && container.chemical_id == chemical_id)
+        .try_fold(Quantity::new(0.0, unit), |total, container| total.checked_add(&container.remaining_quantity()))
+}
+
+#[cfg(test)]
+mod quantity_tests {
+
+    use std::str::FromStr;
+
+    use chemical_inventory::ChemicalInventory;
+    use id::{ChemicalId, LocationId};
+    use quantity::{total_on_hand, Dimension, Quantity, Unit};
+    use test_fixtures;
+
+    fn container(chemical_id: ChemicalId, container_size: f64, unit: Unit, percent_remaining: f64, active: bool)
+        -> ChemicalInventory
+    {
+        ChemicalInventory {
+            container_size,
+            unit,
+            percent_remaining,
+            active,
+            ..test_fixtures::container(chemical_id, LocationId::generate())
+        }
+    }
+
+    fn assert_close(quantity: Quantity, amount: f64, unit: Unit) {
+        assert_eq!(quantity.unit, unit);
+        assert!((quantity.amount - amount).abs() < 1e-6, "{} != {} {}", quantity, amount, unit);
+    }
+
+    #[test]
+    fn test_unit_from_str() {
+        assert_eq!(Unit::from_str("Gallon"), Ok(Unit::Gallon));
+        assert_eq!(Unit::from_str("grams"), Ok(Unit::Gram));
+        assert_eq!(Unit::from_str("Oz"), Ok(Unit::Ounce));
+        assert_eq!(Unit::from_str("fl oz"), Ok(Unit::FluidOunce));
+        assert_eq!(Unit::from_str("mL"), Ok(Unit::Milliliter));
+        assert!(Unit::from_str("furlong").is_err());
+    }
+
+    #[test]
+    fn test_unit_dimension() {
+        assert_eq!(Unit::Pound.dimension(), Dimension::Mass);
+        assert_eq!(Unit::Quart.dimension(), Dimension::Volume);
+        assert_eq!(Unit::Each.dimension(), Dimension::Count);
+    }
+
+    #[test]
+    fn test_quantity_convert_to() {
+        assert_close(Quantity::new(1.0, Unit::Gallon).convert_to(Unit::Liter).unwrap(), 3.785411784, Unit::Liter);
+        assert_close(Quantity::new(2.5, Unit::Kilogram).convert_to(Unit::Gram).unwrap(), 2500.0, Unit::Gram);
+        assert_close(Quantity::new(1.0, Unit::Pound).convert_to(Unit::Ounce).unwrap(), 16.0, Unit::Ounce);
+        assert!(Quantity::new(1.0, Unit::Liter).convert_to(Unit::Gram).is_err());
+    }
+
+    #[test]
+    fn test_remaining_quantity() {
+        assert_close(container(ChemicalId::generate(), 4.0, Unit::Liter, 25.0, true).remaining_quantity(), 1.0, Unit::Liter);
+    }
+
+    #[test]
+    fn test_total_on_hand() {
+        let acetone = ChemicalId::generate();
+        let salt = ChemicalId::generate();
+        let inventory = vec![
+            container(acetone, 1.0, Unit::Gallon, 50.0, true),
+            container(salt, 2.0, Unit::Kilogram, 100.0, true),
+            container(acetone, 500.0, Unit::Milliliter, 100.0, true),
+            container(acetone, 4.0, Unit::Liter, 100.0, false)
+        ];
+
+        assert_close(total_on_hand(acetone, &inventory, Unit::Liter).unwrap(), 1.892705892 + 0.5, Unit::Liter);
+        assert_close(total_on_hand(salt, &inventory, Unit::Gram).unwrap(), 2000.0, Unit::Gram);
+        assert_close(total_on_hand(ChemicalId::generate(), &inventory, Unit::Liter).unwrap(), 0.0, Unit::Liter);
+        assert!(total_on_hand(acetone, &inventory, Unit::Gram).is_err());
+    }
+}
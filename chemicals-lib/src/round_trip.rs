@@ -0,0 +1,343 @@
+//! Property tests that every entity survives `get_fields` -> `from_fields`.
+
+use std::fmt::Debug;
+use std::str::FromStr;
+
+use chrono::{Duration, NaiveDate};
+use proptest::prelude::*;
+use proptest::sample::{select, subsequence};
+use uuid::Uuid;
+
+use acid_base::AcidBase;
+use cas_number::CasNumber;
+use chemical::Chemical;
+use chemical_component::ChemicalComponent;
+use chemical_hazard::ChemicalHazard;
+use chemical_inventory::ChemicalInventory;
+use chemical_pictogram::ChemicalPictogram;
+use chemical_precaution::ChemicalPrecaution;
+use component::Component;
+use container_type::{ContainerType, CONTAINER_TYPES};
+use hazard::Hazard;
+use hazard_catalog::HAZARD_STATEMENTS;
+use hazard_rating::{HmisRating, NfpaRating, NfpaSpecial};
+use manufacturer::Manufacturer;
+use manufacturer_chemical::ManufacturerChemical;
+use pictogram::Pictogram;
+use precaution::Precaution;
+use precaution_catalog::{PRECAUTIONARY_COMBINATIONS, PRECAUTIONARY_STATEMENTS};
+use quantity::Unit;
+use shelf_life::LifeSpan;
+use signal_word::SignalWord;
+use state_of_matter::StateOfMatter;
+use storage_location::{CabinetType, LocationKind, StorageLocation};
+
+use database_lib::interface::Entry;
+
+fn id<T>() -> impl Strategy<Value = T>
+    where T: FromStr<Err = String> + Debug {
+    any::<u128>().prop_map(|n| T::from_str(&Uuid::from_u128(n).to_string()).unwrap())
+}
+
+fn text() -> impl Strategy<Value = String> {
+    "\\PC{0,24}"
+}
+
+fn cas_number() -> impl Strategy<Value = CasNumber> {
+    (1000u64..100_000_000).prop_map(|body| {
+        (0..10)
+            .filter_map(|check| CasNumber::from_str(&format!("{}{}", body, check)).ok())
+            .next()
+            .unwrap()
+    })
+}
+
+fn life_span() -> impl Strategy<Value = LifeSpan> {
+    prop_oneof![
+        any::<u32>().prop_map(LifeSpan::Days),
+        any::<u32>().prop_map(LifeSpan::Weeks),
+        any::<u32>().prop_map(LifeSpan::Months),
+        any::<u32>().prop_map(LifeSpan::Years),
+        Just(LifeSpan::Indefinite)
+    ]
+}
+
+fn signal_word() -> impl Strategy<Value = SignalWord> {
+    select(vec![SignalWord::None, SignalWord::Warning, SignalWord::Danger])
+}
+
+fn state_of_matter() -> impl Strategy<Value = StateOfMatter> {
+    select(vec![StateOfMatter::Solid, StateOfMatter::Powder, StateOfMatter::Liquid, StateOfMatter::CryogenicLiquid,
+        StateOfMatter::Gas, StateOfMatter::CompressedGas, StateOfMatter::Aerosol])
+}
+
+fn unit() -> impl Strategy<Value = Unit> {
+    select(vec![Unit::Milligram, Unit::Gram, Unit::Kilogram, Unit::Ounce, Unit::Pound, Unit::Milliliter, Unit::Liter,
+        Unit::FluidOunce, Unit::Pint, Unit::Quart, Unit::Gallon, Unit::Each])
+}
+
+fn cabinet_type() -> impl Strategy<Value = CabinetType> {
+    select(vec![CabinetType::FlammableCabinet, CabinetType::CorrosiveCabinet, CabinetType::Refrigerator,
+        CabinetType::ExplosionProofRefrigerator, CabinetType::GasCage])
+}
+
+fn container_type() -> impl Strategy<Value = ContainerType> {
+    prop_oneof![
+        select(CONTAINER_TYPES.to_vec()),
+        "[A-Z][a-z]{1,8}( [A-Z][a-z]{1,8})?"
+            .prop_filter("names a known container", |text| ContainerType::from_str(text).ok() == Some(ContainerType::Other(text.clone())))
+            .prop_map(ContainerType::Other)
+    ]
+}
+
+/// A date followed by a later date `days` on, if there is one.
+fn later(date: Option<NaiveDate>, days: Option<i64>) -> Option<NaiveDate> {
+    match (date, days) {
+        (Some(date), Some(days)) => Some(date + Duration::days(days)),
+        _ => None
+    }
+}
+
+/// A temperature in degrees Celsius, if one is recorded.
+fn temperature() -> impl Strategy<Value = Option<f64>> {
+    proptest::option::of(-273.15..2000.0)
+}
+
+fn nfpa_rating() -> impl Strategy<Value = Option<NfpaRating>> {
+    let special = subsequence(vec![NfpaSpecial::WaterReactive, NfpaSpecial::Oxidizer, NfpaSpecial::SimpleAsphyxiant], 0..=3);
+    proptest::option::of((0..=4u8, 0..=4u8, 0..=4u8, special)
+        .prop_map(|(health, flammability, instability, special)| NfpaRating { health, flammability, instability, special }))
+}
+
+fn hmis_rating() -> impl Strategy<Value = Option<HmisRating>> {
+    proptest::option::of((0..=4u8, any::<bool>(), 0..=4u8, 0..=4u8)
+        .prop_map(|(health, chronic, flammability, physical)| HmisRating { health, chronic, flammability, physical }))
+}
+
+fn acid_base() -> impl Strategy<Value = AcidBase> {
+    select(vec![AcidBase::Acid, AcidBase::Base])
+}
+
+fn chemical() -> impl Strategy<Value = Chemical> {
+    let flags = (any::<bool>(), any::<bool>(), any::<bool>());
+    (id(), text(), text(), state_of_matter(), text(), text(), proptest::option::of(life_span()), proptest::option::of(life_span()), flags, signal_word(),
+        (temperature(), temperature()), (nfpa_rating(), hmis_rating(), proptest::option::of(acid_base())))
+        .prop_map(|(id, chemical_name, purpose, state_of_matter, msds_sds_path, qr_code, opened_life_span,
+            unopened_life_span, (controlled_substance, restricted_substance, petroleum_base), signal_word,
+            (flash_point, boiling_point), (nfpa_rating, hmis_rating, acid_base))| Chemical {
+            id,
+            chemical_name,
+            purpose,
+            state_of_matter,
+            msds_sds_path,
+            qr_code,
+            opened_life_span,
+            unopened_life_span,
+            controlled_substance,
+            restricted_substance,
+            petroleum_base,
+            signal_word,
+            flash_point,
+            boiling_point,
+            nfpa_rating,
+            hmis_rating,
+            acid_base
+        })
+}
+
+/// Inventory rows whose dates are always in order, so they pass `validate_chronology`.
+fn chemical_inventory() -> impl Strategy<Value = ChemicalInventory> {
+    let dates = (proptest::option::of(0i64..20_000), proptest::option::of(0i64..400), proptest::option::of(0i64..400),
+        proptest::option::of(0i64..400), proptest::option::of(0i64..400), proptest::option::of(0i64..4000));
+    (id(), id(), id(), text(), dates, text(), any::<bool>(), container_type(), 0.0..1.0e6, unit(), 0.0..=100.0)
+        .prop_map(|(id, chemical_id, location_id, lot_number, (purchase, arrival, open, disposal, removal, expiration),
+            disposal_method, active, container_type, container_size, unit, percent_remaining)| {
+            let purchase_date = purchase.map(|days| NaiveDate::from_ymd_opt(1970, 1, 1).unwrap() + Duration::days(days));
+            let arrival_date = later(purchase_date, arrival);
+            let open_date = later(arrival_date, open);
+            let disposal_date = later(open_date, disposal);
+            let removal_date = later(open_date, removal);
+            ChemicalInventory {
+                id,
+                chemical_id,
+                location_id,
+                lot_number,
+                purchase_date,
+                arrival_date,
+                open_date,
+                expiration_date: later(arrival_date.or(purchase_date), expiration),
+                disposal_date,
+                removal_date,
+                disposal_method,
+                active: active && disposal_date.is_none() && removal_date.is_none(),
+                container_type,
+                container_size,
+                unit,
+                percent_remaining
+            }
+        })
+}
+
+fn component() -> impl Strategy<Value = Component> {
+    (id(), text(), text(), proptest::option::of(cas_number()), text(), text())
+        .prop_map(|(id, chemical_name, common_name, cas_number, substance_number, trade_secret_number)| Component {
+            id,
+            chemical_name,
+            common_name,
+            cas_number,
+            substance_number,
+            trade_secret_number
+        })
+}
+
+fn hazard() -> impl Strategy<Value = Hazard> {
+    let codes: Vec<String> = HAZARD_STATEMENTS.iter().map(|entry| entry.code.to_string()).collect();
+    (id(), proptest::option::of(select(codes)), text(), signal_word())
+        .prop_map(|(id, code, statement, signal_word)| Hazard { id, code, statement, signal_word })
+}
+
+fn precaution() -> impl Strategy<Value = Precaution> {
+    let codes: Vec<String> = PRECAUTIONARY_STATEMENTS.iter().map(|entry| entry.code)
+        .chain(PRECAUTIONARY_COMBINATIONS.iter().cloned())
+        .map(|code| code.to_string())
+        .collect();
+    (id(), proptest::option::of(select(codes)), text())
+        .prop_map(|(id, code, statement)| Precaution { id, code, statement })
+}
+
+fn pictogram() -> impl Strategy<Value = Pictogram> {
+    (id(), text(), text())
+        .prop_map(|(id, picture_name, pictogram_path)| Pictogram { id, picture_name, pictogram_path })
+}
+
+fn manufacturer() -> impl Strategy<Value = Manufacturer> {
+    (id(), text(), text(), text(), text())
+        .prop_map(|(id, company_name, address, phone_number, website)| Manufacturer {
+            id,
+            company_name,
+            address,
+            phone_number,
+            website
+        })
+}
+
+fn chemical_component() -> impl Strategy<Value = ChemicalComponent> {
+    let range = (0.0..=100.0f64).prop_flat_map(|min| (Just(min), min..=100.0f64));
+    (id(), id(), id(), range)
+        .prop_map(|(id, chemical_id, component_id, (minimum_concentration, maximum_concentration))| ChemicalComponent {
+            id,
+            chemical_id,
+            component_id,
+            minimum_concentration,
+            maximum_concentration
+        })
+}
+
+fn chemical_hazard() -> impl Strategy<Value = ChemicalHazard> {
+    (id(), id(), id()).prop_map(|(id, chemical_id, hazard_id)| ChemicalHazard { id, chemical_id, hazard_id })
+}
+
+fn chemical_precaution() -> impl Strategy<Value = ChemicalPrecaution> {
+    (id(), id(), id()).prop_map(|(id, chemical_id, precaution_id)| ChemicalPrecaution { id, chemical_id, precaution_id })
+}
+
+fn chemical_pictogram() -> impl Strategy<Value = ChemicalPictogram> {
+    (id(), id(), id()).prop_map(|(id, chemical_id, pictogram_id)| ChemicalPictogram { id, chemical_id, pictogram_id })
+}
+
+fn manufacturer_chemical() -> impl Strategy<Value = ManufacturerChemical> {
+    (id(), id(), id(), text())
+        .prop_map(|(id, manufacturer_id, chemical_id, manufacturer_number)| ManufacturerChemical {
+            id,
+            manufacturer_id,
+            chemical_id,
+            manufacturer_number
+        })
+}
+
+/// Locations that pass `validate_level`: only buildings lack a parent and
+/// only cabinets have a cabinet type.
+fn storage_location() -> impl Strategy<Value = StorageLocation> {
+    let kind = select(vec![LocationKind::Building, LocationKind::Room, LocationKind::Cabinet, LocationKind::Shelf]);
+    (id(), id(), kind, text(), proptest::option::of(cabinet_type()))
+        .prop_map(|(id, parent_id, kind, name, cabinet_type)| StorageLocation {
+            id,
+            parent_id: if kind == LocationKind::Building { None } else { Some(parent_id) },
+            kind,
+            name,
+            cabinet_type: if kind == LocationKind::Cabinet { cabinet_type } else { None }
+        })
+}
+
+fn assert_fields_round_trip<T>(entry: &T) -> Result<(), TestCaseError>
+    where T: Entry + PartialEq + Debug {
+    prop_assert_eq!(&T::from_fields(&entry.get_fields()).map_err(TestCaseError::fail)?, entry);
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn chemical_round_trips(entry in chemical()) {
+        assert_fields_round_trip(&entry)?;
+    }
+
+    #[test]
+    fn chemical_component_round_trips(entry in chemical_component()) {
+        assert_fields_round_trip(&entry)?;
+    }
+
+    #[test]
+    fn chemical_hazard_round_trips(entry in chemical_hazard()) {
+        assert_fields_round_trip(&entry)?;
+    }
+
+    #[test]
+    fn chemical_inventory_round_trips(entry in chemical_inventory()) {
+        assert_fields_round_trip(&entry)?;
+    }
+
+    #[test]
+    fn chemical_pictogram_round_trips(entry in chemical_pictogram()) {
+        assert_fields_round_trip(&entry)?;
+    }
+
+    #[test]
+    fn chemical_precaution_round_trips(entry in chemical_precaution()) {
+        assert_fields_round_trip(&entry)?;
+    }
+
+    #[test]
+    fn component_round_trips(entry in component()) {
+        assert_fields_round_trip(&entry)?;
+    }
+
+    #[test]
+    fn hazard_round_trips(entry in hazard()) {
+        assert_fields_round_trip(&entry)?;
+    }
+
+    #[test]
+    fn manufacturer_round_trips(entry in manufacturer()) {
+        assert_fields_round_trip(&entry)?;
+    }
+
+    #[test]
+    fn manufacturer_chemical_round_trips(entry in manufacturer_chemical()) {
+        assert_fields_round_trip(&entry)?;
+    }
+
+    #[test]
+    fn pictogram_round_trips(entry in pictogram()) {
+        assert_fields_round_trip(&entry)?;
+    }
+
+    #[test]
+    fn precaution_round_trips(entry in precaution()) {
+        assert_fields_round_trip(&entry)?;
+    }
+
+    #[test]
+    fn storage_location_round_trips(entry in storage_location()) {
+        assert_fields_round_trip(&entry)?;
+    }
+}
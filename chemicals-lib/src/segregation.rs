@@ -0,0 +1,323 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fmt;
+
+use acid_base::AcidBase;
+use chemical::Chemical;
+use chemical_inventory::ChemicalInventory;
+use chemical_record::{ChemicalRecord, RecordSource};
+use hazard::Hazard;
+use hazard_catalog::HazardClass;
+use id::{ChemicalId, InventoryId, LocationId};
+use state_of_matter::StateOfMatter;
+use storage_location::{enclosing_cabinet, StorageLocation};
+
+/// The storage group a chemical is shelved with. The order is the order of
+/// precedence when a chemical falls into more than one group.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub enum StorageGroup {
+    Explosive,
+    Pyrophoric,
+    WaterReactive,
+    OrganicPeroxide,
+    FlammableGas,
+    OxidizingGas,
+    CompressedGas,
+    Flammable,
+    Oxidizer,
+    Acid,
+    Base,
+    Corrosive,
+    Toxic,
+    General
+}
+
+impl Display for StorageGroup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StorageGroup::Explosive => write!(f, "Explosives"),
+            StorageGroup::Pyrophoric => write!(f, "Pyrophorics"),
+            StorageGroup::WaterReactive => write!(f, "Water-reactives"),
+            StorageGroup::OrganicPeroxide => write!(f, "Organic peroxides"),
+            StorageGroup::FlammableGas => write!(f, "Flammable gases"),
+            StorageGroup::OxidizingGas => write!(f, "Oxidizing gases"),
+            StorageGroup::CompressedGas => write!(f, "Compressed gases"),
+            StorageGroup::Flammable => write!(f, "Flammables"),
+            StorageGroup::Oxidizer => write!(f, "Oxidizers"),
+            StorageGroup::Acid => write!(f, "Acids"),
+            StorageGroup::Base => write!(f, "Bases"),
+            StorageGroup::Corrosive => write!(f, "Corrosives"),
+            StorageGroup::Toxic => write!(f, "Toxics"),
+            StorageGroup::General => write!(f, "General")
+        }
+    }
+}
+
+/// Pairs of storage groups that must not share a cabinet. Each pair is
+/// listed once; `incompatible` checks both orders.
+pub const SEGREGATION_MATRIX: &[(StorageGroup, StorageGroup)] = &[
+    (StorageGroup::Explosive, StorageGroup::Pyrophoric),
+    (StorageGroup::Explosive, StorageGroup::WaterReactive),
+    (StorageGroup::Explosive, StorageGroup::OrganicPeroxide),
+    (StorageGroup::Explosive, StorageGroup::FlammableGas),
+    (StorageGroup::Explosive, StorageGroup::OxidizingGas),
+    (StorageGroup::Explosive, StorageGroup::CompressedGas),
+    (StorageGroup::Explosive, StorageGroup::Flammable),
+    (StorageGroup::Explosive, StorageGroup::Oxidizer),
+    (StorageGroup::Explosive, StorageGroup::Acid),
+    (StorageGroup::Explosive, StorageGroup::Base),
+    (StorageGroup::Explosive, StorageGroup::Corrosive),
+    (StorageGroup::Explosive, StorageGroup::Toxic),
+    (StorageGroup::Pyrophoric, StorageGroup::OrganicPeroxide),
+    (StorageGroup::Pyrophoric, StorageGroup::FlammableGas),
+    (StorageGroup::Pyrophoric, StorageGroup::OxidizingGas),
+    (StorageGroup::Pyrophoric, StorageGroup::Flammable),
+    (StorageGroup::Pyrophoric, StorageGroup::Oxidizer),
+    (StorageGroup::Pyrophoric, StorageGroup::Acid),
+    (StorageGroup::Pyrophoric, StorageGroup::Base),
+    (StorageGroup::Pyrophoric, StorageGroup::Corrosive),
+    (StorageGroup::WaterReactive, StorageGroup::OrganicPeroxide),
+    (StorageGroup::WaterReactive, StorageGroup::OxidizingGas),
+    (StorageGroup::WaterReactive, StorageGroup::Oxidizer),
+    (StorageGroup::WaterReactive, StorageGroup::Acid),
+    (StorageGroup::WaterReactive, StorageGroup::Base),
+    (StorageGroup::WaterReactive, StorageGroup::Corrosive),
+    (StorageGroup::OrganicPeroxide, StorageGroup::FlammableGas),
+    (StorageGroup::OrganicPeroxide, StorageGroup::OxidizingGas),
+    (StorageGroup::OrganicPeroxide, StorageGroup::Flammable),
+    (StorageGroup::OrganicPeroxide, StorageGroup::Oxidizer),
+    (StorageGroup::OrganicPeroxide, StorageGroup::Acid),
+    (StorageGroup::OrganicPeroxide, StorageGroup::Base),
+    (StorageGroup::OrganicPeroxide, StorageGroup::Corrosive),
+    (StorageGroup::FlammableGas, StorageGroup::OxidizingGas),
+    (StorageGroup::FlammableGas, StorageGroup::Oxidizer),
+    (StorageGroup::OxidizingGas, StorageGroup::Flammable),
+    (StorageGroup::Flammable, StorageGroup::Oxidizer),
+    (StorageGroup::Acid, StorageGroup::Base),
+    (StorageGroup::Acid, StorageGroup::Corrosive),
+    (StorageGroup::Base, StorageGroup::Corrosive)
+];
+
+/// Whether groups `a` and `b` must be kept apart.
+pub fn incompatible(a: StorageGroup, b: StorageGroup) -> bool {
+    SEGREGATION_MATRIX.iter().any(|&(first, second)| (first, second) == (a, b) || (first, second) == (b, a))
+}
+
+/// Hazard codes outside the physical hazard classes that still make a
+/// chemical water-reactive: "Reacts violently with water" and "Contact
+/// with water liberates toxic gas".
+const WATER_REACTIVE_SUPPLEMENTAL: [&str; 2] = ["EUH014", "EUH029"];
+
+/// Tells acids from bases among corrosives by the chemical's recorded
+/// `acid_base`, as the hazard classes do not. A corrosive with none recorded
+/// stays a plain corrosive and is kept away from both.
+fn corrosive_group(chemical: &Chemical) -> StorageGroup {
+    match chemical.acid_base {
+        Some(AcidBase::Acid) => StorageGroup::Acid,
+        Some(AcidBase::Base) => StorageGroup::Base,
+        None => StorageGroup::Corrosive
+    }
+}
+
+/// Every storage group `chemical` falls into given its `hazards`, most
+/// restrictive first. Flammables and oxidizers that are gases go with the
+/// gases, and a gas with no other group is a compressed gas. A chemical with
+/// no storage hazards is `General`.
+pub fn storage_groups(chemical: &Chemical, hazards: &[Hazard]) -> Vec<StorageGroup> {
+    let is_gas = chemical.state_of_matter.phase() == StateOfMatter::Gas && chemical.state_of_matter != StateOfMatter::Aerosol;
+    let flammable = if is_gas { StorageGroup::FlammableGas } else { StorageGroup::Flammable };
+    let oxidizer = if is_gas { StorageGroup::OxidizingGas } else { StorageGroup::Oxidizer };
+
+    let mut groups: Vec<StorageGroup> = hazards.iter()
+        .filter_map(|hazard| hazard.catalog_entry())
+        .filter_map(|entry| match entry.hazard_class {
+            HazardClass::Explosive | HazardClass::DesensitizedExplosive => Some(StorageGroup::Explosive),
+            HazardClass::PyrophoricGas | HazardClass::PyrophoricLiquidOrSolid | HazardClass::SelfHeating =>
+                Some(StorageGroup::Pyrophoric),
+            HazardClass::WaterReactive => Some(StorageGroup::WaterReactive),
+            HazardClass::SelfReactiveOrOrganicPeroxide => Some(StorageGroup::OrganicPeroxide),
+            HazardClass::FlammableGas | HazardClass::ChemicallyUnstableGas => Some(StorageGroup::FlammableGas),
+            HazardClass::Aerosol | HazardClass::ChemicalUnderPressure if entry.category == "3" =>
+                Some(StorageGroup::CompressedGas),
+            HazardClass::Aerosol | HazardClass::ChemicalUnderPressure => Some(StorageGroup::FlammableGas),
+            HazardClass::FlammableLiquid | HazardClass::FlammableSolid => Some(flammable),
+            HazardClass::OxidizingGas => Some(StorageGroup::OxidizingGas),
+            HazardClass::OxidizingLiquidOrSolid => Some(oxidizer),
+            HazardClass::GasUnderPressure => Some(StorageGroup::CompressedGas),
+            HazardClass::CorrosiveToMetals | HazardClass::SkinCorrosion => Some(corrosive_group(chemical)),
+            HazardClass::AcuteToxicityOral | HazardClass::AcuteToxicityDermal | HazardClass::AcuteToxicityInhalation
+                if ["1", "2", "3"].contains(&entry.category) => Some(StorageGroup::Toxic),
+            HazardClass::Supplemental if WATER_REACTIVE_SUPPLEMENTAL.contains(&entry.code) =>
+                Some(StorageGroup::WaterReactive),
+            _ => None
+        })
+        .collect();
+
+    let gas_groups = [StorageGroup::FlammableGas, StorageGroup::OxidizingGas, StorageGroup::CompressedGas];
+    if is_gas && !groups.iter().any(|group| gas_groups.contains(group)) {
+        groups.push(StorageGroup::CompressedGas);
+    }
+    if groups.is_empty() {
+        groups.push(StorageGroup::General);
+    }
+    groups.sort();
+    groups.dedup();
+    groups
+}
+
+/// The single group `chemical` is shelved under: the most restrictive of
+/// its `storage_groups`.
+pub fn storage_group(chemical: &Chemical, hazards: &[Hazard]) -> StorageGroup {
+    storage_groups(chemical, hazards)[0]
+}
+
+/// Two active containers of different chemicals stored in the same cabinet
+/// whose storage groups must be kept apart. `groups` holds the incompatible
+/// group of each container, in the order of `first` and `second`.
+#[derive(PartialEq, Clone, Debug)]
+pub struct SegregationConflict {
+    pub cabinet_id: LocationId,
+    pub first: InventoryId,
+    pub second: InventoryId,
+    pub groups: (StorageGroup, StorageGroup)
+}
+
+impl Display for SegregationConflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}) and {} ({}) share cabinet {}",
+            self.first, self.groups.0, self.second, self.groups.1, self.cabinet_id)
+    }
+}
+
+/// Reports every pair of active containers in `inventory` that share a
+/// cabinet and hold chemicals in incompatible storage groups. Containers on
+/// open shelving, outside any cabinet, are not checked. The chemical of each
+/// container is loaded from `source`, and a missing one is an error.
+pub fn check_segregation<S: RecordSource + ?Sized>(source: &S, locations: &[StorageLocation],
+    inventory: &[ChemicalInventory]) -> Result<Vec<SegregationConflict>, String>
+{
+    let mut groups: HashMap<ChemicalId, Vec<StorageGroup>> = HashMap::new();
+    let mut stored = Vec::new();
+    for container in inventory.iter().filter(|container| container.active) {
+        if let Some(cabinet) = enclosing_cabinet(container.location_id, locations) {
+            if let Entry::Vacant(entry) = groups.entry(container.chemical_id) {
+                entry.insert(ChemicalRecord::load(container.chemical_id, source)?.storage_groups());
+            }
+            stored.push((cabinet.id, container));
+        }
+    }
+
+    let mut conflicts = Vec::new();
+    for (i, &(cabinet_id, first)) in stored.iter().enumerate() {
+        for &(other_cabinet_id, second) in stored[i + 1..].iter() {
+            if cabinet_id != other_cabinet_id || first.chemical_id == second.chemical_id {
+                continue;
+            }
+            let pair = groups[&first.chemical_id].iter()
+                .flat_map(|&a| groups[&second.chemical_id].iter().map(move |&b| (a, b)))
+                .find(|&(a, b)| incompatible(a, b));
+            if let Some(pair) = pair {
+                conflicts.push(SegregationConflict {
+                    cabinet_id,
+                    first: first.id,
+                    second: second.id,
+                    groups: pair
+                });
+            }
+        }
+    }
+    Ok(conflicts)
+}
+
+#[cfg(test)]
+mod segregation_tests {
+
+    use acid_base::AcidBase;
+    use chemical_record::ChemicalTables;
+    use segregation::{check_segregation, incompatible, storage_group, storage_groups, StorageGroup};
+    use state_of_matter::StateOfMatter;
+    use storage_location::LocationKind;
+    use test_fixtures::{add, chemical, container, hazards, location};
+
+    #[test]
+    fn test_storage_groups() {
+        let acetone = chemical("Acetone", StateOfMatter::Liquid);
+        assert_eq!(storage_groups(&acetone, &hazards(&["H225", "H319", "H336"])), vec![StorageGroup::Flammable]);
+
+        let mut nitric_acid = chemical("Nitric acid", StateOfMatter::Liquid);
+        nitric_acid.acid_base = Some(AcidBase::Acid);
+        assert_eq!(storage_groups(&nitric_acid, &hazards(&["H272", "H290", "H314", "H331"])),
+            vec![StorageGroup::Oxidizer, StorageGroup::Acid, StorageGroup::Toxic]);
+
+        let mut sodium_hydroxide = chemical("Sodium hydroxide", StateOfMatter::Solid);
+        sodium_hydroxide.acid_base = Some(AcidBase::Base);
+        assert_eq!(storage_group(&sodium_hydroxide, &hazards(&["H290", "H314"])), StorageGroup::Base);
+
+        let hydrochloric_acid = chemical("Hydrochloric acid", StateOfMatter::Liquid);
+        assert_eq!(storage_group(&hydrochloric_acid, &hazards(&["H290", "H314"])), StorageGroup::Corrosive);
+
+        let sodium = chemical("Sodium", StateOfMatter::Solid);
+        assert_eq!(storage_group(&sodium, &hazards(&["H260", "H314", "EUH014"])), StorageGroup::WaterReactive);
+
+        let hydrogen = chemical("Hydrogen", StateOfMatter::CompressedGas);
+        assert_eq!(storage_groups(&hydrogen, &hazards(&["H220", "H280"])), vec![StorageGroup::FlammableGas, StorageGroup::CompressedGas]);
+
+        let nitrogen = chemical("Nitrogen", StateOfMatter::CompressedGas);
+        assert_eq!(storage_groups(&nitrogen, &[]), vec![StorageGroup::CompressedGas]);
+
+        let sodium_chloride = chemical("Sodium chloride", StateOfMatter::Solid);
+        assert_eq!(storage_groups(&sodium_chloride, &hazards(&["H319"])), vec![StorageGroup::General]);
+    }
+
+    #[test]
+    fn test_incompatible() {
+        assert!(incompatible(StorageGroup::Flammable, StorageGroup::Oxidizer));
+        assert!(incompatible(StorageGroup::Oxidizer, StorageGroup::Flammable));
+        assert!(incompatible(StorageGroup::Base, StorageGroup::Acid));
+        assert!(incompatible(StorageGroup::Corrosive, StorageGroup::Acid));
+        assert!(incompatible(StorageGroup::Corrosive, StorageGroup::Base));
+        assert!(!incompatible(StorageGroup::Flammable, StorageGroup::Flammable));
+        assert!(!incompatible(StorageGroup::General, StorageGroup::Explosive));
+    }
+
+    #[test]
+    fn test_check_segregation() {
+        let mut tables = ChemicalTables::default();
+        let acetone = chemical("Acetone", StateOfMatter::Liquid);
+        let nitric_acid = chemical("Nitric acid", StateOfMatter::Liquid);
+        let sodium_chloride = chemical("Sodium chloride", StateOfMatter::Solid);
+        add(&mut tables, &acetone, &["H225"]);
+        add(&mut tables, &nitric_acid, &["H272", "H314"]);
+        add(&mut tables, &sodium_chloride, &[]);
+
+        let building = location(LocationKind::Building, "Science Center", None);
+        let room = location(LocationKind::Room, "204", Some(&building));
+        let cabinet = location(LocationKind::Cabinet, "A", Some(&room));
+        let shelf = location(LocationKind::Shelf, "Top", Some(&cabinet));
+        let other_cabinet = location(LocationKind::Cabinet, "B", Some(&room));
+        let locations = vec![building, room.clone(), cabinet.clone(), shelf.clone(), other_cabinet.clone()];
+
+        let mut disposed = container(nitric_acid.id, cabinet.id);
+        disposed.active = false;
+        let inventory = vec![
+            container(acetone.id, shelf.id),
+            container(nitric_acid.id, cabinet.id),
+            container(acetone.id, cabinet.id),
+            container(sodium_chloride.id, cabinet.id),
+            container(nitric_acid.id, other_cabinet.id),
+            container(acetone.id, room.id),
+            disposed
+        ];
+
+        let conflicts = check_segregation(&tables, &locations, &inventory).unwrap();
+        let pairs: Vec<_> = conflicts.iter().map(|conflict| (conflict.first, conflict.second)).collect();
+        assert_eq!(pairs, vec![(inventory[0].id, inventory[1].id), (inventory[1].id, inventory[2].id)]);
+        assert_eq!(conflicts[0].cabinet_id, cabinet.id);
+        assert_eq!(conflicts[0].groups, (StorageGroup::Flammable, StorageGroup::Oxidizer));
+        assert_eq!(conflicts[1].groups, (StorageGroup::Oxidizer, StorageGroup::Flammable));
+
+        let unknown = chemical("Unknown", StateOfMatter::Liquid);
+        assert!(check_segregation(&tables, &locations, &[container(unknown.id, cabinet.id)]).is_err());
+    }
+}
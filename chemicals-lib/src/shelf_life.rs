@@ -0,0 +1,187 @@
+use std::fmt::Display;
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Duration, Months, NaiveDate};
+
+use chemical::Chemical;
+use chemical_inventory::ChemicalInventory;
+
+/// How long a chemical stays usable, e.g. "6 months", "2 years" or "indefinite".
+/// A blank life span is not a `LifeSpan`; fields whose life span may be
+/// unknown are `Option<LifeSpan>`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum LifeSpan {
+    Days(u32),
+    Weeks(u32),
+    Months(u32),
+    Years(u32),
+    Indefinite
+}
+
+impl LifeSpan {
+    /// The date a life span starting on `start` runs out, or `None` if it never does.
+    pub fn end_date(&self, start: NaiveDate) -> Option<NaiveDate> {
+        match *self {
+            LifeSpan::Days(days) => start.checked_add_signed(Duration::days(i64::from(days))),
+            LifeSpan::Weeks(weeks) => start.checked_add_signed(Duration::weeks(i64::from(weeks))),
+            LifeSpan::Months(months) => start.checked_add_months(Months::new(months)),
+            LifeSpan::Years(years) => years.checked_mul(12).and_then(|months| start.checked_add_months(Months::new(months))),
+            LifeSpan::Indefinite => None
+        }
+    }
+}
+
+impl Display for LifeSpan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (amount, unit) = match *self {
+            LifeSpan::Days(amount) => (amount, "day"),
+            LifeSpan::Weeks(amount) => (amount, "week"),
+            LifeSpan::Months(amount) => (amount, "month"),
+            LifeSpan::Years(amount) => (amount, "year"),
+            LifeSpan::Indefinite => return write!(f, "Indefinite")
+        };
+
+        if amount == 1 {
+            write!(f, "{} {}", amount, unit)
+        } else {
+            write!(f, "{} {}s", amount, unit)
+        }
+    }
+}
+
+impl FromStr for LifeSpan {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.trim().to_lowercase();
+        if lower == "indefinite" {
+            return Ok(LifeSpan::Indefinite);
+        }
+
+        let mut parts = lower.split_whitespace();
+        let amount = parts.next()
+            .and_then(|amount| amount.parse::<u32>().ok())
+            .ok_or_else(|| format!("Invalid life span: {}", s))?;
+        let unit = parts.next().ok_or_else(|| format!("Missing unit in life span: {}", s))?;
+        if parts.next().is_some() {
+            return Err(format!("Invalid life span: {}", s));
+        }
+
+        match unit.strip_suffix('s').unwrap_or(unit) {
+            "day" => Ok(LifeSpan::Days(amount)),
+            "week" => Ok(LifeSpan::Weeks(amount)),
+            "month" => Ok(LifeSpan::Months(amount)),
+            "year" => Ok(LifeSpan::Years(amount)),
+            _ => Err(format!("Unknown unit in life span: {}", s))
+        }
+    }
+}
+
+/// The date an inventory container expires: the earliest of the manufacturer
+/// expiration date, the arrival date plus the unopened life span and the open
+/// date plus the opened life span. An unknown life span limits nothing.
+/// `None` means nothing limits its shelf life.
+pub fn expiration_date(chemical: &Chemical, inventory: &ChemicalInventory) -> Option<NaiveDate> {
+    let unopened_expiration = inventory.arrival_date
+        .and_then(|arrival| chemical.unopened_life_span.and_then(|span| span.end_date(arrival)));
+    let opened_expiration = inventory.open_date
+        .and_then(|open| chemical.opened_life_span.and_then(|span| span.end_date(open)));
+
+    vec![inventory.expiration_date, unopened_expiration, opened_expiration]
+        .into_iter()
+        .flatten()
+        .min()
+}
+
+#[cfg(test)]
+mod shelf_life_tests {
+
+    use std::str::FromStr;
+
+    use chrono::NaiveDate;
+
+    use chemical::Chemical;
+    use chemical_inventory::ChemicalInventory;
+    use date::parse_date;
+    use id::{ChemicalId, LocationId};
+    use shelf_life::{expiration_date, LifeSpan};
+    use state_of_matter::StateOfMatter;
+    use test_fixtures::{chemical, container};
+
+    fn inventory(arrival_date: &str, open_date: &str, expiration_date: &str) -> ChemicalInventory {
+        ChemicalInventory {
+            arrival_date: parse_date(arrival_date).unwrap(),
+            open_date: parse_date(open_date).unwrap(),
+            expiration_date: parse_date(expiration_date).unwrap(),
+            ..container(ChemicalId::generate(), LocationId::generate())
+        }
+    }
+
+    #[test]
+    fn test_lifespan_from_str() {
+        assert_eq!(LifeSpan::from_str("6 months"), Ok(LifeSpan::Months(6)));
+        assert_eq!(LifeSpan::from_str("2 Years"), Ok(LifeSpan::Years(2)));
+        assert_eq!(LifeSpan::from_str("1 day"), Ok(LifeSpan::Days(1)));
+        assert_eq!(LifeSpan::from_str("indefinite"), Ok(LifeSpan::Indefinite));
+        assert!(LifeSpan::from_str("a while").is_err());
+        assert!(LifeSpan::from_str("6 fortnights").is_err());
+        assert!(LifeSpan::from_str("2 yearss").is_err());
+        assert!(LifeSpan::from_str("").is_err());
+    }
+
+    #[test]
+    fn test_lifespan_display_round_trip() {
+        let spans = [LifeSpan::Days(15), LifeSpan::Weeks(1), LifeSpan::Months(6), LifeSpan::Years(2), LifeSpan::Indefinite];
+
+        for span in spans.iter() {
+            assert_eq!(LifeSpan::from_str(&span.to_string()), Ok(*span));
+        }
+    }
+
+    #[test]
+    fn test_lifespan_end_date() {
+        let start = NaiveDate::from_ymd_opt(2019, 1, 31).unwrap();
+
+        assert_eq!(LifeSpan::Months(1).end_date(start), NaiveDate::from_ymd_opt(2019, 2, 28));
+        assert_eq!(LifeSpan::Years(2).end_date(start), NaiveDate::from_ymd_opt(2021, 1, 31));
+        assert_eq!(LifeSpan::Indefinite.end_date(start), None);
+    }
+
+    #[test]
+    fn test_expiration_date_picks_earliest() {
+        let chemical = Chemical {
+            opened_life_span: Some(LifeSpan::Months(6)),
+            unopened_life_span: Some(LifeSpan::Years(2)),
+            ..chemical("Acetone", StateOfMatter::Liquid)
+        };
+
+        let unopened = inventory("2019-01-01", "", "2025-01-01");
+        assert_eq!(expiration_date(&chemical, &unopened), NaiveDate::from_ymd_opt(2021, 1, 1));
+
+        let opened = inventory("2019-01-01", "2019-03-01", "2025-01-01");
+        assert_eq!(expiration_date(&chemical, &opened), NaiveDate::from_ymd_opt(2019, 9, 1));
+
+        let recalled = inventory("2019-01-01", "2019-03-01", "2019-02-01");
+        assert_eq!(expiration_date(&chemical, &recalled), NaiveDate::from_ymd_opt(2019, 2, 1));
+    }
+
+    #[test]
+    fn test_expiration_date_indefinite() {
+        let chemical = chemical("Acetone", StateOfMatter::Liquid);
+
+        assert_eq!(expiration_date(&chemical, &inventory("2019-01-01", "2019-03-01", "")), None);
+    }
+
+    #[test]
+    fn test_expiration_date_unknown_life_span() {
+        let chemical = Chemical {
+            opened_life_span: None,
+            unopened_life_span: Some(LifeSpan::Years(1)),
+            ..chemical("Acetone", StateOfMatter::Liquid)
+        };
+
+        assert_eq!(expiration_date(&chemical, &inventory("2019-01-01", "2019-03-01", "")), NaiveDate::from_ymd_opt(2020, 1, 1));
+        assert_eq!(expiration_date(&chemical, &inventory("", "2019-03-01", "")), None);
+    }
+}
@@ -0,0 +1,74 @@
+use std::fmt::Display;
+use std::fmt;
+use std::str::FromStr;
+
+/// GHS signal word, ordered from least to most severe.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug, Default)]
+pub enum SignalWord {
+    #[default]
+    None,
+    Warning,
+    Danger
+}
+
+impl SignalWord {
+    /// The most severe signal word out of `words`, or `SignalWord::None` if there are none.
+    pub fn most_severe<I>(words: I) -> SignalWord
+        where I: IntoIterator<Item = SignalWord>
+    {
+        words.into_iter().max().unwrap_or(SignalWord::None)
+    }
+}
+
+impl Display for SignalWord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SignalWord::None => write!(f, "None"),
+            SignalWord::Warning => write!(f, "Warning"),
+            SignalWord::Danger => write!(f, "Danger")
+        }
+    }
+}
+
+impl FromStr for SignalWord {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "" | "none" => Ok(SignalWord::None),
+            "warning" => Ok(SignalWord::Warning),
+            "danger" => Ok(SignalWord::Danger),
+            _ => Err(format!("Unknown signal word: {}", s))
+        }
+    }
+}
+
+#[cfg(test)]
+mod signal_word_tests {
+
+    use std::str::FromStr;
+
+    use signal_word::SignalWord;
+
+    #[test]
+    fn test_signalword_from_str() {
+        assert_eq!(SignalWord::from_str("Danger"), Ok(SignalWord::Danger));
+        assert_eq!(SignalWord::from_str("WARNING"), Ok(SignalWord::Warning));
+        assert_eq!(SignalWord::from_str(""), Ok(SignalWord::None));
+        assert_eq!(SignalWord::from_str("None"), Ok(SignalWord::None));
+        assert!(SignalWord::from_str("Caution").is_err());
+    }
+
+    #[test]
+    fn test_signalword_ordering() {
+        assert!(SignalWord::Danger > SignalWord::Warning);
+        assert!(SignalWord::Warning > SignalWord::None);
+    }
+
+    #[test]
+    fn test_signalword_most_severe() {
+        let words = vec![SignalWord::Warning, SignalWord::Danger, SignalWord::None];
+        assert_eq!(SignalWord::most_severe(words), SignalWord::Danger);
+        assert_eq!(SignalWord::most_severe(Vec::new()), SignalWord::None);
+    }
+}
@@ -0,0 +1,162 @@
+use chemical::Chemical;
+use chemical_component::ChemicalComponent;
+use chemical_hazard::ChemicalHazard;
+use chemical_inventory::ChemicalInventory;
+use chemical_pictogram::ChemicalPictogram;
+use chemical_precaution::ChemicalPrecaution;
+use component::Component;
+use entry::{ChemicalsEntry, Column, ColumnType};
+use hazard::Hazard;
+use manufacturer::Manufacturer;
+use manufacturer_chemical::ManufacturerChemical;
+use pictogram::Pictogram;
+use precaution::Precaution;
+use storage_location::StorageLocation;
+
+/// The SQL flavour to write DDL for.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum SqlDialect {
+    MySql,
+    Sqlite
+}
+
+impl SqlDialect {
+    /// Quotes a table or column name. Column names are the field display
+    /// names, which have spaces and slashes in them.
+    pub fn quote(&self, identifier: &str) -> String {
+        match self {
+            SqlDialect::MySql => format!("`{}`", identifier.replace('`', "``")),
+            SqlDialect::Sqlite => format!("\"{}\"", identifier.replace('"', "\"\""))
+        }
+    }
+
+    /// MySQL can only index bounded text, so indexed text columns and ids
+    /// get a fixed width there.
+    fn column_type(&self, column: &Column) -> &'static str {
+        match (self, column.column_type) {
+            (SqlDialect::MySql, ColumnType::Text) if column.indexed => "VARCHAR(255)",
+            (SqlDialect::MySql, ColumnType::Text) => "TEXT",
+            (SqlDialect::MySql, ColumnType::Boolean) => "BOOLEAN",
+            (SqlDialect::MySql, ColumnType::Number) => "DOUBLE",
+            (SqlDialect::MySql, ColumnType::Date) => "DATE",
+            (SqlDialect::MySql, ColumnType::Id) => "CHAR(36)",
+            (SqlDialect::Sqlite, ColumnType::Boolean) => "INTEGER",
+            (SqlDialect::Sqlite, ColumnType::Number) => "REAL",
+            (SqlDialect::Sqlite, _) => "TEXT"
+        }
+    }
+}
+
+/// `table_column` in lowercase with anything but letters and digits as `_`.
+fn index_name(table: &str, column: &str) -> String {
+    let column: String = column.to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("{}_{}", table, column)
+}
+
+/// The `CREATE TABLE` statement for `T`, followed by a `CREATE INDEX` for
+/// each foreign key and each column marked `index`.
+///
+/// Columns for `Option` fields are nullable; a blank value in them is meant
+/// to be stored as `NULL`. Every other column is `NOT NULL`.
+pub fn create_table<T: ChemicalsEntry>(dialect: SqlDialect) -> String {
+    let table = dialect.quote(T::TABLE);
+    let columns = T::columns();
+
+    let mut definitions: Vec<String> = columns.iter()
+        .map(|column| {
+            let null = if column.nullable { "" } else { " NOT NULL" };
+            format!("{} {}{}", dialect.quote(column.name), dialect.column_type(column), null)
+        })
+        .collect();
+    definitions.push(format!("PRIMARY KEY ({})", dialect.quote(T::PRIMARY_KEY)));
+    for column in columns.iter() {
+        if let Some(reference) = column.references {
+            definitions.push(format!("FOREIGN KEY ({}) REFERENCES {} ({})",
+                dialect.quote(column.name), dialect.quote(reference.table), dialect.quote(reference.column)));
+        }
+    }
+
+    let options = match dialect {
+        SqlDialect::MySql => " ENGINE=InnoDB DEFAULT CHARSET=utf8mb4",
+        SqlDialect::Sqlite => ""
+    };
+    let mut sql = format!("CREATE TABLE {} (\n    {}\n){};\n", table, definitions.join(",\n    "), options);
+
+    for column in columns.iter().filter(|column| column.indexed || column.references.is_some()) {
+        sql.push_str(&format!("CREATE INDEX {} ON {} ({});\n",
+            dialect.quote(&index_name(T::TABLE, column.name)), table, dialect.quote(column.name)));
+    }
+    sql
+}
+
+/// The DDL for every entity, with each table created after the tables its
+/// foreign keys point to.
+pub fn create_schema(dialect: SqlDialect) -> String {
+    [
+        create_table::<Chemical>(dialect),
+        create_table::<Component>(dialect),
+        create_table::<Hazard>(dialect),
+        create_table::<Precaution>(dialect),
+        create_table::<Pictogram>(dialect),
+        create_table::<Manufacturer>(dialect),
+        create_table::<StorageLocation>(dialect),
+        create_table::<ChemicalInventory>(dialect),
+        create_table::<ChemicalComponent>(dialect),
+        create_table::<ChemicalHazard>(dialect),
+        create_table::<ChemicalPrecaution>(dialect),
+        create_table::<ChemicalPictogram>(dialect),
+        create_table::<ManufacturerChemical>(dialect)
+    ].join("\n")
+}
+
+#[cfg(test)]
+mod sql_schema_tests {
+
+    use chemical_hazard::ChemicalHazard;
+    use chemical_inventory::ChemicalInventory;
+    use sql_schema::{create_schema, create_table, SqlDialect};
+
+    #[test]
+    fn test_create_table_sqlite() {
+        assert_eq!(create_table::<ChemicalHazard>(SqlDialect::Sqlite), "\
+CREATE TABLE \"chemical_hazard\" (
+    \"ID\" TEXT NOT NULL,
+    \"Chemical ID\" TEXT NOT NULL,
+    \"Hazard ID\" TEXT NOT NULL,
+    PRIMARY KEY (\"ID\"),
+    FOREIGN KEY (\"Chemical ID\") REFERENCES \"chemical\" (\"ID\"),
+    FOREIGN KEY (\"Hazard ID\") REFERENCES \"hazard\" (\"ID\")
+);
+CREATE INDEX \"chemical_hazard_chemical_id\" ON \"chemical_hazard\" (\"Chemical ID\");
+CREATE INDEX \"chemical_hazard_hazard_id\" ON \"chemical_hazard\" (\"Hazard ID\");
+");
+    }
+
+    #[test]
+    fn test_create_table_mysql() {
+        let sql = create_table::<ChemicalInventory>(SqlDialect::MySql);
+        assert!(sql.starts_with("CREATE TABLE `chemical_inventory` (\n    `ID` CHAR(36) NOT NULL,\n"));
+        assert!(sql.contains("    `Lot Number` VARCHAR(255) NOT NULL,\n"));
+        assert!(sql.contains("    `Purchase Date` DATE,\n"));
+        assert!(sql.contains("    `Disposal Method` TEXT NOT NULL,\n"));
+        assert!(sql.contains("    `Active` BOOLEAN NOT NULL,\n"));
+        assert!(sql.contains("    `Container Size` DOUBLE NOT NULL,\n"));
+        assert!(sql.contains("    FOREIGN KEY (`Chemical ID`) REFERENCES `chemical` (`ID`),\n"));
+        assert!(sql.contains("    FOREIGN KEY (`Location ID`) REFERENCES `storage_location` (`ID`)\n) ENGINE=InnoDB"));
+        assert!(sql.contains("CREATE INDEX `chemical_inventory_lot_number` ON `chemical_inventory` (`Lot Number`);\n"));
+    }
+
+    #[test]
+    fn test_create_schema_orders_references() {
+        let sql = create_schema(SqlDialect::Sqlite);
+        let position = |table: &str| sql.find(&format!("CREATE TABLE \"{}\"", table)).unwrap();
+        assert!(position("chemical") < position("chemical_inventory"));
+        assert!(position("storage_location") < position("chemical_inventory"));
+        assert!(position("hazard") < position("chemical_hazard"));
+        assert!(position("manufacturer") < position("manufacturer_chemical"));
+        assert_eq!(sql.matches("CREATE TABLE").count(), 13);
+    }
+}
@@ -0,0 +1,454 @@
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::path::Path;
+
+use rusqlite::types::{Null, ToSqlOutput, ValueRef};
+use rusqlite::{params_from_iter, Connection, ToSql};
+
+use database_lib::interface::{Table, Value};
+
+use chemical::Chemical;
+use chemical_component::{ChemicalComponent, ChemicalComponentFields};
+use chemical_hazard::{ChemicalHazard, ChemicalHazardFields};
+use chemical_pictogram::{ChemicalPictogram, ChemicalPictogramFields};
+use chemical_precaution::{ChemicalPrecaution, ChemicalPrecautionFields};
+use chemical_record::RecordSource;
+use component::Component;
+use entry::{ChemicalsEntry, Column, ColumnType};
+use error::ChemicalsError;
+use hazard::Hazard;
+use id::{ChemicalId, ComponentId, HazardId, ManufacturerId, PictogramId, PrecautionId};
+use manufacturer::Manufacturer;
+use manufacturer_chemical::{ManufacturerChemical, ManufacturerChemicalFields};
+use pictogram::Pictogram;
+use precaution::Precaution;
+use sql_schema::{create_schema, SqlDialect};
+
+fn storage_error(error: rusqlite::Error) -> ChemicalsError {
+    ChemicalsError::Storage(error.to_string())
+}
+
+/// A `Value` bound for `column`: booleans as 0 or 1 and a blank optional
+/// column as `NULL`.
+struct Parameter {
+    column: Column,
+    value: Value
+}
+
+impl ToSql for Parameter {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        match self.value {
+            Value::String(ref s) if self.column.nullable && s.trim().is_empty() => Null.to_sql(),
+            Value::String(ref s) => Ok(ToSqlOutput::from(s.as_str())),
+            Value::Boolean(b) => Ok(ToSqlOutput::from(b))
+        }
+    }
+}
+
+/// The `Value` `from_fields` expects for a stored cell.
+fn column_value(column: &Column, cell: ValueRef) -> Value {
+    match (column.column_type, cell) {
+        (ColumnType::Boolean, ValueRef::Integer(i)) => Value::Boolean(i != 0),
+        (_, ValueRef::Null) => Value::String(String::new()),
+        (_, ValueRef::Integer(i)) => Value::String(i.to_string()),
+        (_, ValueRef::Real(f)) => Value::String(f.to_string()),
+        (_, ValueRef::Text(text)) | (_, ValueRef::Blob(text)) => Value::String(String::from_utf8_lossy(text).into_owned())
+    }
+}
+
+/// Every chemicals-lib entity kept in a single SQLite file, for machines
+/// with no database server. Tables are laid out by `create_schema` and
+/// foreign keys are enforced, so a link to a missing row is refused.
+pub struct SqliteStore {
+    connection: Connection
+}
+
+impl SqliteStore {
+    /// Opens the store at `path`, creating the file and its tables if needed.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<SqliteStore, ChemicalsError> {
+        SqliteStore::new(Connection::open(path).map_err(storage_error)?)
+    }
+
+    /// A store that lives only as long as the value, for tests and scratch work.
+    pub fn open_in_memory() -> Result<SqliteStore, ChemicalsError> {
+        SqliteStore::new(Connection::open_in_memory().map_err(storage_error)?)
+    }
+
+    fn new(connection: Connection) -> Result<SqliteStore, ChemicalsError> {
+        connection.execute_batch("PRAGMA foreign_keys = ON;").map_err(storage_error)?;
+        let tables: i64 = connection
+            .query_row("SELECT count(*) FROM sqlite_master WHERE type = 'table'", [], |row| row.get(0))
+            .map_err(storage_error)?;
+        if tables == 0 {
+            connection.execute_batch(&create_schema(SqlDialect::Sqlite)).map_err(storage_error)?;
+        }
+        Ok(SqliteStore { connection })
+    }
+
+    fn column_list<T: ChemicalsEntry>() -> String {
+        T::columns().iter()
+            .map(|column| SqlDialect::Sqlite.quote(column.name))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    fn primary_key<T: ChemicalsEntry>() -> Result<Column, ChemicalsError> {
+        T::columns().into_iter()
+            .find(|column| column.name == T::PRIMARY_KEY)
+            .ok_or_else(|| ChemicalsError::MissingColumn { field: T::PRIMARY_KEY.to_string() })
+    }
+
+    /// The primary key of `entry`, ready to bind.
+    fn key_parameter<T: ChemicalsEntry>(entry: &T) -> Result<Parameter, ChemicalsError> {
+        T::columns().into_iter()
+            .zip(entry.get_fields())
+            .find(|&(column, _)| column.primary_key)
+            .map(|(column, value)| Parameter { column, value })
+            .ok_or_else(|| ChemicalsError::MissingColumn { field: T::PRIMARY_KEY.to_string() })
+    }
+
+    /// Adds `entry` as a new row.
+    pub fn insert<T: ChemicalsEntry>(&self, entry: &T) -> Result<(), ChemicalsError> {
+        SqliteStore::insert_into(&self.connection, entry)
+    }
+
+    /// Adds `entry` as a new row through `connection`, which may be an open
+    /// transaction.
+    fn insert_into<T: ChemicalsEntry>(connection: &Connection, entry: &T) -> Result<(), ChemicalsError> {
+        let columns = T::columns();
+        let placeholders: Vec<String> = (1..=columns.len()).map(|i| format!("?{}", i)).collect();
+        let sql = format!("INSERT INTO {} ({}) VALUES ({})",
+            SqlDialect::Sqlite.quote(T::TABLE), SqliteStore::column_list::<T>(), placeholders.join(", "));
+
+        let parameters = columns.iter().zip(entry.get_fields())
+            .map(|(&column, value)| Parameter { column, value });
+        connection.execute(&sql, params_from_iter(parameters)).map_err(storage_error)?;
+        Ok(())
+    }
+
+    /// Adds every entry in one transaction, so either all of them are stored
+    /// or, on the first failure, none are.
+    pub fn insert_all<T: ChemicalsEntry>(&mut self, entries: &[T]) -> Result<(), ChemicalsError> {
+        let transaction = self.connection.transaction().map_err(storage_error)?;
+        for entry in entries {
+            SqliteStore::insert_into(&transaction, entry)?;
+        }
+        transaction.commit().map_err(storage_error)
+    }
+
+    /// Overwrites the row with the same primary key as `entry`. Returns
+    /// whether there was such a row.
+    pub fn update<T: ChemicalsEntry>(&self, entry: &T) -> Result<bool, ChemicalsError> {
+        let columns = T::columns();
+        let assignments: Vec<String> = columns.iter().enumerate()
+            .map(|(i, column)| format!("{} = ?{}", SqlDialect::Sqlite.quote(column.name), i + 1))
+            .collect();
+        let sql = format!("UPDATE {} SET {} WHERE {} = ?{}", SqlDialect::Sqlite.quote(T::TABLE),
+            assignments.join(", "), SqlDialect::Sqlite.quote(T::PRIMARY_KEY), columns.len() + 1);
+
+        let mut parameters: Vec<Parameter> = columns.iter().zip(entry.get_fields())
+            .map(|(&column, value)| Parameter { column, value })
+            .collect();
+        parameters.push(SqliteStore::key_parameter(entry)?);
+        let changed = self.connection.execute(&sql, params_from_iter(parameters)).map_err(storage_error)?;
+        Ok(changed > 0)
+    }
+
+    /// Removes the row with the same primary key as `entry`. Returns whether
+    /// there was such a row.
+    pub fn delete<T: ChemicalsEntry>(&self, entry: &T) -> Result<bool, ChemicalsError> {
+        let sql = format!("DELETE FROM {} WHERE {} = ?1",
+            SqlDialect::Sqlite.quote(T::TABLE), SqlDialect::Sqlite.quote(T::PRIMARY_KEY));
+        let changed = self.connection.execute(&sql, [SqliteStore::key_parameter(entry)?]).map_err(storage_error)?;
+        Ok(changed > 0)
+    }
+
+    fn select<T: ChemicalsEntry>(&self, filter: Option<(Column, Value)>) -> Result<Vec<T>, ChemicalsError> {
+        let columns = T::columns();
+        let mut sql = format!("SELECT {} FROM {}", SqliteStore::column_list::<T>(), SqlDialect::Sqlite.quote(T::TABLE));
+        let mut parameters = Vec::new();
+        if let Some((column, value)) = filter {
+            let null = column.nullable && value == Value::String(String::new());
+            sql.push_str(&format!(" WHERE {} {}", SqlDialect::Sqlite.quote(column.name),
+                if null { "IS NULL" } else { "= ?1" }));
+            if !null {
+                parameters.push(Parameter { column, value });
+            }
+        }
+
+        let mut statement = self.connection.prepare(&sql).map_err(storage_error)?;
+        let mut rows = statement.query(params_from_iter(parameters)).map_err(storage_error)?;
+        let mut entries = Vec::new();
+        while let Some(row) = rows.next().map_err(storage_error)? {
+            let mut values = Vec::with_capacity(columns.len());
+            for (i, column) in columns.iter().enumerate() {
+                values.push(column_value(column, row.get_ref(i).map_err(storage_error)?));
+            }
+            entries.push(T::try_from_fields(&values)?);
+        }
+        Ok(entries)
+    }
+
+    /// Every stored row of `T`.
+    pub fn all<T: ChemicalsEntry>(&self) -> Result<Vec<T>, ChemicalsError> {
+        self.select(None)
+    }
+
+    /// The rows of `T` whose `field` holds `value`, compared the way the
+    /// column is stored.
+    pub fn find<T>(&self, field: T::FieldNames, value: Value) -> Result<Vec<T>, ChemicalsError>
+        where T: ChemicalsEntry, T::FieldNames: Display
+    {
+        let name = field.to_string();
+        let columns = T::columns();
+        match columns.into_iter().find(|column| column.name == name) {
+            Some(column) => self.select(Some((column, value))),
+            None => Err(ChemicalsError::MissingColumn { field: name })
+        }
+    }
+
+    /// The row of `T` with primary key `id`.
+    pub fn get<T: ChemicalsEntry, I: Display>(&self, id: I) -> Result<Option<T>, ChemicalsError> {
+        let key = SqliteStore::primary_key::<T>()?;
+        Ok(self.select(Some((key, Value::String(id.to_string()))))?.into_iter().next())
+    }
+
+    /// The table of `T` as a database_lib `Table`.
+    pub fn table<T: ChemicalsEntry>(&self) -> SqliteTable<'_, T> {
+        SqliteTable {
+            store: self,
+            entries: PhantomData
+        }
+    }
+}
+
+/// One entity's table in a `SqliteStore` behind database_lib's `Table`
+/// interface, so code written for `VecTable` or the MySQL backend can run
+/// on a laptop. Errors are rendered as strings, as that interface has them.
+pub struct SqliteTable<'a, T> {
+    store: &'a SqliteStore,
+    entries: PhantomData<T>
+}
+
+impl<'a, T> Table<T> for SqliteTable<'a, T>
+    where T: ChemicalsEntry, T::FieldNames: Display
+{
+    fn insert(&mut self, entry: T) -> Result<(), String> {
+        self.store.insert(&entry).map_err(String::from)
+    }
+
+    fn update(&mut self, entry: T) -> Result<bool, String> {
+        self.store.update(&entry).map_err(String::from)
+    }
+
+    fn delete(&mut self, entry: &T) -> Result<bool, String> {
+        self.store.delete(entry).map_err(String::from)
+    }
+
+    fn search(&self, field_name: T::FieldNames, value: Value) -> Result<Vec<T>, String> {
+        self.store.find(field_name, value).map_err(String::from)
+    }
+
+    fn get_entries(&self) -> Result<Vec<T>, String> {
+        self.store.all().map_err(String::from)
+    }
+}
+
+/// Each lookup is one query on a primary key or an indexed foreign key.
+impl RecordSource for SqliteStore {
+    fn chemical(&self, id: ChemicalId) -> Result<Option<Chemical>, ChemicalsError> {
+        self.get(id)
+    }
+
+    fn component(&self, id: ComponentId) -> Result<Option<Component>, ChemicalsError> {
+        self.get(id)
+    }
+
+    fn hazard(&self, id: HazardId) -> Result<Option<Hazard>, ChemicalsError> {
+        self.get(id)
+    }
+
+    fn precaution(&self, id: PrecautionId) -> Result<Option<Precaution>, ChemicalsError> {
+        self.get(id)
+    }
+
+    fn pictogram(&self, id: PictogramId) -> Result<Option<Pictogram>, ChemicalsError> {
+        self.get(id)
+    }
+
+    fn manufacturer(&self, id: ManufacturerId) -> Result<Option<Manufacturer>, ChemicalsError> {
+        self.get(id)
+    }
+
+    fn chemical_components(&self, chemical_id: ChemicalId) -> Result<Vec<ChemicalComponent>, ChemicalsError> {
+        self.find(ChemicalComponentFields::ChemicalId, Value::String(chemical_id.to_string()))
+    }
+
+    fn chemical_hazards(&self, chemical_id: ChemicalId) -> Result<Vec<ChemicalHazard>, ChemicalsError> {
+        self.find(ChemicalHazardFields::ChemicalId, Value::String(chemical_id.to_string()))
+    }
+
+    fn chemical_precautions(&self, chemical_id: ChemicalId) -> Result<Vec<ChemicalPrecaution>, ChemicalsError> {
+        self.find(ChemicalPrecautionFields::ChemicalId, Value::String(chemical_id.to_string()))
+    }
+
+    fn chemical_pictograms(&self, chemical_id: ChemicalId) -> Result<Vec<ChemicalPictogram>, ChemicalsError> {
+        self.find(ChemicalPictogramFields::ChemicalId, Value::String(chemical_id.to_string()))
+    }
+
+    fn manufacturer_chemicals(&self, chemical_id: ChemicalId) -> Result<Vec<ManufacturerChemical>, ChemicalsError> {
+        self.find(ManufacturerChemicalFields::ChemicalId, Value::String(chemical_id.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod sqlite_store_tests {
+
+    use chrono::NaiveDate;
+    use tempfile::tempdir;
+
+    use chemical::{Chemical, ChemicalFields};
+    use chemical_hazard::ChemicalHazard;
+    use chemical_inventory::{ChemicalInventory, ChemicalInventoryFields};
+    use chemical_record::ChemicalRecord;
+    use error::ChemicalsError;
+    use id::{ChemicalHazardId, ChemicalId, InventoryId, LocationId};
+    use shelf_life::LifeSpan;
+    use sqlite_store::SqliteStore;
+    use state_of_matter::StateOfMatter;
+    use storage_location::{LocationKind, StorageLocation};
+    use test_fixtures;
+
+    use database_lib::interface::{Table, Value};
+
+    fn chemical() -> Chemical {
+        Chemical {
+            purpose: "Cleaning".to_string(),
+            opened_life_span: Some(LifeSpan::Years(1)),
+            restricted_substance: true,
+            ..test_fixtures::chemical("Acetone", StateOfMatter::Liquid)
+        }
+    }
+
+    fn building() -> StorageLocation {
+        test_fixtures::location(LocationKind::Building, "Science Center", None)
+    }
+
+    fn inventory(chemical_id: ChemicalId, location_id: LocationId, lot_number: &str) -> ChemicalInventory {
+        ChemicalInventory {
+            lot_number: lot_number.to_string(),
+            purchase_date: NaiveDate::from_ymd_opt(2018, 12, 1),
+            container_size: 0.1,
+            percent_remaining: 37.5,
+            ..test_fixtures::container(chemical_id, location_id)
+        }
+    }
+
+    #[test]
+    fn test_store_persists_to_file() {
+        let directory = tempdir().unwrap();
+        let path = directory.path().join("chemicals.sqlite");
+        let chemical = chemical();
+        let building = building();
+        let containers = vec![inventory(chemical.id, building.id, "A1"), inventory(chemical.id, building.id, "B2")];
+
+        {
+            let mut store = SqliteStore::open(&path).unwrap();
+            store.insert(&chemical).unwrap();
+            store.insert(&building).unwrap();
+            store.insert_all(&containers).unwrap();
+        }
+
+        let store = SqliteStore::open(&path).unwrap();
+        assert_eq!(store.all::<Chemical>().unwrap(), vec![chemical.clone()]);
+        assert_eq!(store.get::<ChemicalInventory, _>(containers[1].id).unwrap(), Some(containers[1].clone()));
+        assert_eq!(store.find::<ChemicalInventory>(ChemicalInventoryFields::LotNumber, Value::String("A1".to_string())).unwrap(),
+            vec![containers[0].clone()]);
+        assert_eq!(store.find::<ChemicalInventory>(ChemicalInventoryFields::OpenDate, Value::String("".to_string())).unwrap().len(), 2);
+        assert_eq!(store.get::<ChemicalInventory, _>(InventoryId::generate()).unwrap(), None);
+    }
+
+    #[test]
+    fn test_update_and_delete() {
+        let store = SqliteStore::open_in_memory().unwrap();
+        let chemical = chemical();
+        let building = building();
+        let mut container = inventory(chemical.id, building.id, "A1");
+        store.insert(&chemical).unwrap();
+        store.insert(&building).unwrap();
+        store.insert(&container).unwrap();
+
+        container.open_date = NaiveDate::from_ymd_opt(2019, 1, 2);
+        container.active = false;
+        assert!(store.update(&container).unwrap());
+        assert_eq!(store.all::<ChemicalInventory>().unwrap(), vec![container.clone()]);
+
+        assert!(store.delete(&container).unwrap());
+        assert!(!store.delete(&container).unwrap());
+        assert!(store.all::<ChemicalInventory>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_foreign_keys_are_enforced() {
+        let mut store = SqliteStore::open_in_memory().unwrap();
+        let chemical = chemical();
+        let building = building();
+        store.insert(&chemical).unwrap();
+        store.insert(&building).unwrap();
+
+        assert!(store.insert(&inventory(ChemicalId::generate(), building.id, "A1")).is_err());
+        assert!(store.insert(&inventory(chemical.id, LocationId::generate(), "A1")).is_err());
+        match store.insert_all(&[inventory(chemical.id, building.id, "A1"), inventory(ChemicalId::generate(), building.id, "B2")]) {
+            Err(ChemicalsError::Storage(ref message)) => assert!(message.contains("FOREIGN KEY"), "{}", message),
+            ref other => panic!("expected the foreign key failure, got {:?}", other)
+        }
+        assert!(store.all::<ChemicalInventory>().unwrap().is_empty());
+
+        store.insert(&inventory(chemical.id, building.id, "A1")).unwrap();
+        assert!(store.delete(&chemical).is_err());
+    }
+
+    #[test]
+    fn test_load_chemical_record() {
+        let mut store = SqliteStore::open_in_memory().unwrap();
+        let chemical = chemical();
+        let other = Chemical { id: ChemicalId::generate(), ..chemical.clone() };
+        let hazards = test_fixtures::hazards(&["H225", "H319"]);
+        store.insert(&chemical).unwrap();
+        store.insert(&other).unwrap();
+        store.insert_all(&hazards).unwrap();
+        store.insert_all(&[
+            ChemicalHazard { id: ChemicalHazardId::generate(), chemical_id: chemical.id, hazard_id: hazards[0].id },
+            ChemicalHazard { id: ChemicalHazardId::generate(), chemical_id: chemical.id, hazard_id: hazards[1].id },
+            ChemicalHazard { id: ChemicalHazardId::generate(), chemical_id: other.id, hazard_id: hazards[1].id }
+        ]).unwrap();
+
+        let record = ChemicalRecord::load(chemical.id, &store).unwrap();
+        assert_eq!(record.chemical, chemical);
+        assert_eq!(record.hazards.len(), 2);
+        assert_eq!(ChemicalRecord::load(other.id, &store).unwrap().hazards, vec![hazards[1].clone()]);
+        match ChemicalRecord::load(ChemicalId::generate(), &store) {
+            Err(ChemicalsError::NotFound(_)) => {},
+            result => panic!("Expected NotFound, got {:?}", result.map(|record| record.chemical))
+        }
+    }
+
+    #[test]
+    fn test_table_interface() {
+        let store = SqliteStore::open_in_memory().unwrap();
+        let acetone = chemical();
+        let mut table = store.table::<Chemical>();
+        table.insert(acetone.clone()).unwrap();
+        assert!(table.insert(acetone.clone()).is_err());
+
+        let mut renamed = acetone.clone();
+        renamed.chemical_name = "Propanone".to_string();
+        assert!(table.update(renamed.clone()).unwrap());
+        assert_eq!(table.search(ChemicalFields::ChemicalName, Value::String("Propanone".to_string())).unwrap(),
+            vec![renamed.clone()]);
+        assert_eq!(store.get::<Chemical, _>(acetone.id).unwrap(), Some(renamed.clone()));
+
+        assert!(table.delete(&renamed).unwrap());
+        assert!(table.get_entries().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt::Display;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum StateOfMatter {
+    Solid,
+    Powder,
+    Liquid,
+    CryogenicLiquid,
+    Gas,
+    CompressedGas,
+    Aerosol
+}
+
+impl StateOfMatter {
+    /// The plain solid, liquid or gas phase a sub-kind belongs to.
+    pub fn phase(&self) -> StateOfMatter {
+        match self {
+            StateOfMatter::Solid | StateOfMatter::Powder => StateOfMatter::Solid,
+            StateOfMatter::Liquid | StateOfMatter::CryogenicLiquid => StateOfMatter::Liquid,
+            StateOfMatter::Gas | StateOfMatter::CompressedGas | StateOfMatter::Aerosol => StateOfMatter::Gas
+        }
+    }
+}
+
+impl Display for StateOfMatter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StateOfMatter::Solid => write!(f, "Solid"),
+            StateOfMatter::Powder => write!(f, "Powder"),
+            StateOfMatter::Liquid => write!(f, "Liquid"),
+            StateOfMatter::CryogenicLiquid => write!(f, "Cryogenic Liquid"),
+            StateOfMatter::Gas => write!(f, "Gas"),
+            StateOfMatter::CompressedGas => write!(f, "Compressed Gas"),
+            StateOfMatter::Aerosol => write!(f, "Aerosol")
+        }
+    }
+}
+
+impl FromStr for StateOfMatter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "solid" => Ok(StateOfMatter::Solid),
+            "powder" => Ok(StateOfMatter::Powder),
+            "liquid" => Ok(StateOfMatter::Liquid),
+            "cryogenic liquid" => Ok(StateOfMatter::CryogenicLiquid),
+            "gas" => Ok(StateOfMatter::Gas),
+            "compressed gas" => Ok(StateOfMatter::CompressedGas),
+            "aerosol" => Ok(StateOfMatter::Aerosol),
+            "plasma" => Err("Plasma is not a supported state of matter".to_string()),
+            _ => Err(format!("Unknown state of matter: {}", s))
+        }
+    }
+}
+
+#[cfg(test)]
+mod state_of_matter_tests {
+
+    use std::str::FromStr;
+
+    use state_of_matter::StateOfMatter;
+
+    #[test]
+    fn test_stateofmatter_from_str() {
+        assert_eq!(StateOfMatter::from_str("Liquid"), Ok(StateOfMatter::Liquid));
+        assert_eq!(StateOfMatter::from_str("compressed gas"), Ok(StateOfMatter::CompressedGas));
+        assert_eq!(StateOfMatter::from_str(" Cryogenic Liquid "), Ok(StateOfMatter::CryogenicLiquid));
+        assert!(StateOfMatter::from_str("Plasma").unwrap_err().contains("Plasma"));
+        assert!(StateOfMatter::from_str("Goo").is_err());
+    }
+
+    #[test]
+    fn test_stateofmatter_display_round_trip() {
+        let states = [
+            StateOfMatter::Solid,
+            StateOfMatter::Powder,
+            StateOfMatter::Liquid,
+            StateOfMatter::CryogenicLiquid,
+            StateOfMatter::Gas,
+            StateOfMatter::CompressedGas,
+            StateOfMatter::Aerosol
+        ];
+
+        for state in states.iter() {
+            assert_eq!(StateOfMatter::from_str(&state.to_string()), Ok(*state));
+        }
+    }
+
+    #[test]
+    fn test_stateofmatter_phase() {
+        assert_eq!(StateOfMatter::Powder.phase(), StateOfMatter::Solid);
+        assert_eq!(StateOfMatter::CryogenicLiquid.phase(), StateOfMatter::Liquid);
+        assert_eq!(StateOfMatter::Aerosol.phase(), StateOfMatter::Gas);
+    }
+}
@@ -0,0 +1,281 @@
+use std::fmt::Display;
+use std::fmt;
+use std::str::FromStr;
+
+use chemical_inventory::ChemicalInventory;
+use id::LocationId;
+
+/// The level of a place in the building -> room -> cabinet -> shelf hierarchy.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub enum LocationKind {
+    Building,
+    Room,
+    Cabinet,
+    Shelf
+}
+
+impl LocationKind {
+    /// Whether a location of this kind can sit directly inside one of kind
+    /// `parent`. Shelves can be open shelving in a room or inside a cabinet.
+    pub fn can_be_inside(&self, parent: LocationKind) -> bool {
+        match self {
+            LocationKind::Building => false,
+            LocationKind::Room => parent == LocationKind::Building,
+            LocationKind::Cabinet => parent == LocationKind::Room,
+            LocationKind::Shelf => parent == LocationKind::Room || parent == LocationKind::Cabinet
+        }
+    }
+}
+
+impl Display for LocationKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LocationKind::Building => write!(f, "Building"),
+            LocationKind::Room => write!(f, "Room"),
+            LocationKind::Cabinet => write!(f, "Cabinet"),
+            LocationKind::Shelf => write!(f, "Shelf")
+        }
+    }
+}
+
+impl FromStr for LocationKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "building" => Ok(LocationKind::Building),
+            "room" => Ok(LocationKind::Room),
+            "cabinet" => Ok(LocationKind::Cabinet),
+            "shelf" => Ok(LocationKind::Shelf),
+            _ => Err(format!("Unknown location kind: {}", s))
+        }
+    }
+}
+
+/// Purpose-built storage a cabinet can be.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum CabinetType {
+    FlammableCabinet,
+    CorrosiveCabinet,
+    Refrigerator,
+    ExplosionProofRefrigerator,
+    GasCage
+}
+
+impl Display for CabinetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CabinetType::FlammableCabinet => write!(f, "Flammable Cabinet"),
+            CabinetType::CorrosiveCabinet => write!(f, "Corrosive Cabinet"),
+            CabinetType::Refrigerator => write!(f, "Refrigerator"),
+            CabinetType::ExplosionProofRefrigerator => write!(f, "Explosion-Proof Refrigerator"),
+            CabinetType::GasCage => write!(f, "Gas Cage")
+        }
+    }
+}
+
+impl FromStr for CabinetType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized: String = s.chars()
+            .filter(|c| c.is_alphanumeric())
+            .collect::<String>()
+            .to_lowercase();
+
+        match normalized.as_str() {
+            "flammablecabinet" | "flammable" => Ok(CabinetType::FlammableCabinet),
+            "corrosivecabinet" | "corrosive" | "acidcabinet" => Ok(CabinetType::CorrosiveCabinet),
+            "refrigerator" | "fridge" => Ok(CabinetType::Refrigerator),
+            "explosionproofrefrigerator" | "explosionprooffridge" => Ok(CabinetType::ExplosionProofRefrigerator),
+            "gascage" => Ok(CabinetType::GasCage),
+            _ => Err(format!("Unknown cabinet type: {}", s))
+        }
+    }
+}
+
+/// A building, room, cabinet or shelf. Every location but a building sits
+/// inside a parent; only cabinets have a cabinet type.
+#[derive(PartialEq, Debug, Clone, Entry)]
+#[entry(validate = "validate_level")]
+pub struct StorageLocation {
+    #[field(name = "ID", primary_key)]
+    pub id: LocationId,
+    #[field(name = "Parent ID", references = "StorageLocation")]
+    pub parent_id: Option<LocationId>,
+    #[field(name = "Kind")]
+    pub kind: LocationKind,
+    #[field(name = "Name", index)]
+    pub name: String,
+    #[field(name = "Cabinet Type")]
+    pub cabinet_type: Option<CabinetType>
+}
+
+impl StorageLocation {
+    /// Rejects a building with a parent, any other location without one, and
+    /// a cabinet type on anything but a cabinet.
+    pub fn validate_level(&self) -> Result<(), String> {
+        match (self.kind, self.parent_id) {
+            (LocationKind::Building, Some(_)) => return Err(format!("Building {} cannot be inside another location", self.name)),
+            (LocationKind::Building, None) => {},
+            (kind, None) => return Err(format!("{} {} must be inside another location", kind, self.name)),
+            (_, Some(_)) => {}
+        }
+
+        if self.cabinet_type.is_some() && self.kind != LocationKind::Cabinet {
+            return Err(format!("{} {} cannot have a cabinet type", self.kind, self.name));
+        }
+        Ok(())
+    }
+}
+
+fn find(id: LocationId, locations: &[StorageLocation]) -> Option<&StorageLocation> {
+    locations.iter().find(|location| location.id == id)
+}
+
+/// Checks that every parent exists and that each location nests inside the
+/// right kind of parent, which also rules out cycles.
+pub fn validate_locations(locations: &[StorageLocation]) -> Result<(), String> {
+    for location in locations {
+        location.validate_level()?;
+        if let Some(parent_id) = location.parent_id {
+            match find(parent_id, locations) {
+                Some(parent) if location.kind.can_be_inside(parent.kind) => {},
+                Some(parent) => return Err(format!("{} {} cannot be inside {} {}",
+                    location.kind, location.name, parent.kind, parent.name)),
+                None => return Err(format!("The parent of {} {} does not exist", location.kind, location.name))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The location with `id` and everything it is inside, outermost first.
+pub fn location_path(id: LocationId, locations: &[StorageLocation]) -> Vec<&StorageLocation> {
+    let mut path = Vec::new();
+    let mut next = Some(id);
+    while let Some(location) = next.and_then(|id| find(id, locations)) {
+        if path.iter().any(|visited: &&StorageLocation| visited.id == location.id) {
+            break;
+        }
+        path.push(location);
+        next = location.parent_id;
+    }
+    path.reverse();
+    path
+}
+
+/// Whether `location` is `ancestor` or somewhere inside it.
+pub fn is_within(location: LocationId, ancestor: LocationId, locations: &[StorageLocation]) -> bool {
+    location_path(location, locations).iter().any(|step| step.id == ancestor)
+}
+
+/// The innermost cabinet `location_id` is in, or the location itself if it
+/// is a cabinet. `None` for open shelving in a room.
+pub fn enclosing_cabinet(location_id: LocationId, locations: &[StorageLocation]) -> Option<&StorageLocation> {
+    location_path(location_id, locations).into_iter().rev().find(|step| step.kind == LocationKind::Cabinet)
+}
+
+/// The containers stored at `location_id` or anywhere inside it.
+pub fn inventory_in<'a>(location_id: LocationId, locations: &[StorageLocation], inventory: &'a [ChemicalInventory])
+    -> Vec<&'a ChemicalInventory>
+{
+    inventory.iter()
+        .filter(|container| is_within(container.location_id, location_id, locations))
+        .collect()
+}
+
+/// The containers anywhere in a room called `room`, e.g. "everything in room 204".
+pub fn inventory_in_room<'a>(room: &str, locations: &[StorageLocation], inventory: &'a [ChemicalInventory])
+    -> Vec<&'a ChemicalInventory>
+{
+    let room = room.trim().to_lowercase();
+    inventory.iter()
+        .filter(|container| location_path(container.location_id, locations).iter()
+            .any(|step| step.kind == LocationKind::Room && step.name.trim().to_lowercase() == room))
+        .collect()
+}
+
+#[cfg(test)]
+mod storage_location_tests {
+
+    use std::str::FromStr;
+
+    use id::ChemicalId;
+    use storage_location::{enclosing_cabinet, inventory_in, inventory_in_room, location_path, validate_locations,
+        CabinetType, LocationKind, StorageLocation};
+    use test_fixtures::{container, location};
+
+    use database_lib::interface::Entry;
+    use database_lib::interface::Value;
+
+    #[test]
+    fn test_cabinettype_from_str() {
+        assert_eq!(CabinetType::from_str("Explosion-Proof Refrigerator"), Ok(CabinetType::ExplosionProofRefrigerator));
+        assert_eq!(CabinetType::from_str("flammable"), Ok(CabinetType::FlammableCabinet));
+        assert_eq!(CabinetType::from_str("Gas Cage"), Ok(CabinetType::GasCage));
+        assert!(CabinetType::from_str("Drawer").is_err());
+    }
+
+    #[test]
+    fn test_storagelocation_from_fields() {
+        let building = location(LocationKind::Building, "Rowan Hall", None);
+        let fields = [
+            Value::String("9a3e5c1d-2b4f-4e6a-8c0d-1f2e3a4b5c6d".to_string()),
+            Value::String(building.id.to_string()),
+            Value::String("Cabinet".to_string()),
+            Value::String("Under the hood".to_string()),
+            Value::String("Flammable Cabinet".to_string())
+        ];
+        let cabinet = StorageLocation::from_fields(&fields).unwrap();
+        assert_eq!(cabinet.parent_id, Some(building.id));
+        assert_eq!(cabinet.cabinet_type, Some(CabinetType::FlammableCabinet));
+
+        let mut fields = fields.to_vec();
+        fields[2] = Value::String("Shelf".to_string());
+        assert!(StorageLocation::from_fields(&fields).is_err());
+        fields[1] = Value::String("".to_string());
+        fields[2] = Value::String("Room".to_string());
+        fields[4] = Value::String("".to_string());
+        assert!(StorageLocation::from_fields(&fields).is_err());
+    }
+
+    #[test]
+    fn test_validate_locations() {
+        let building = location(LocationKind::Building, "Rowan Hall", None);
+        let room = location(LocationKind::Room, "204", Some(&building));
+        let cabinet = location(LocationKind::Cabinet, "A", Some(&room));
+        let shelf = location(LocationKind::Shelf, "Top", Some(&cabinet));
+        assert!(validate_locations(&[building.clone(), room.clone(), cabinet.clone(), shelf.clone()]).is_ok());
+
+        assert!(validate_locations(&[room.clone(), cabinet.clone()]).is_err());
+        let misplaced = location(LocationKind::Cabinet, "B", Some(&building));
+        assert!(validate_locations(&[building.clone(), misplaced]).is_err());
+    }
+
+    #[test]
+    fn test_inventory_by_location() {
+        let building = location(LocationKind::Building, "Rowan Hall", None);
+        let room = location(LocationKind::Room, "204", Some(&building));
+        let other_room = location(LocationKind::Room, "205", Some(&building));
+        let mut cabinet = location(LocationKind::Cabinet, "A", Some(&room));
+        cabinet.cabinet_type = Some(CabinetType::CorrosiveCabinet);
+        let shelf = location(LocationKind::Shelf, "Top", Some(&cabinet));
+        let locations = vec![building.clone(), room.clone(), other_room.clone(), cabinet.clone(), shelf.clone()];
+        let inventory = vec![
+            container(ChemicalId::generate(), shelf.id),
+            container(ChemicalId::generate(), room.id),
+            container(ChemicalId::generate(), other_room.id)
+        ];
+
+        let path: Vec<&str> = location_path(shelf.id, &locations).iter().map(|step| step.name.as_str()).collect();
+        assert_eq!(path, vec!["Rowan Hall", "204", "A", "Top"]);
+        assert_eq!(enclosing_cabinet(shelf.id, &locations), Some(&cabinet));
+        assert_eq!(enclosing_cabinet(room.id, &locations), None);
+
+        assert_eq!(inventory_in_room("204", &locations, &inventory), vec![&inventory[0], &inventory[1]]);
+        assert_eq!(inventory_in(cabinet.id, &locations, &inventory), vec![&inventory[0]]);
+        assert_eq!(inventory_in(building.id, &locations, &inventory).len(), 3);
+        assert!(inventory_in_room("301", &locations, &inventory).is_empty());
+    }
+}
@@ -0,0 +1,88 @@
+//! Builders for the entities the unit tests share. Each fills every field
+//! it does not take with a neutral default; tests override the fields they
+//! care about with struct update syntax.
+
+use chemical::Chemical;
+use chemical_hazard::ChemicalHazard;
+use chemical_inventory::ChemicalInventory;
+use chemical_record::ChemicalTables;
+use container_type::ContainerType;
+use hazard::Hazard;
+use id::{ChemicalHazardId, ChemicalId, InventoryId, LocationId};
+use quantity::Unit;
+use shelf_life::LifeSpan;
+use signal_word::SignalWord;
+use state_of_matter::StateOfMatter;
+use storage_location::{LocationKind, StorageLocation};
+
+pub fn chemical(chemical_name: &str, state_of_matter: StateOfMatter) -> Chemical {
+    Chemical {
+        id: ChemicalId::generate(),
+        chemical_name: chemical_name.to_string(),
+        purpose: "".to_string(),
+        state_of_matter,
+        msds_sds_path: "".to_string(),
+        qr_code: "".to_string(),
+        opened_life_span: Some(LifeSpan::Indefinite),
+        unopened_life_span: Some(LifeSpan::Indefinite),
+        controlled_substance: false,
+        restricted_substance: false,
+        petroleum_base: false,
+        signal_word: SignalWord::Danger,
+        flash_point: None,
+        boiling_point: None,
+        nfpa_rating: None,
+        hmis_rating: None,
+        acid_base: None
+    }
+}
+
+/// The catalog hazards for `codes`, which must all be known.
+pub fn hazards(codes: &[&str]) -> Vec<Hazard> {
+    codes.iter().map(|code| Hazard::from_code(code).unwrap()).collect()
+}
+
+/// Adds `chemical` to `tables` with the hazards for `codes` linked.
+pub fn add(tables: &mut ChemicalTables, chemical: &Chemical, codes: &[&str]) {
+    for hazard in hazards(codes) {
+        tables.chemical_hazards.push(ChemicalHazard {
+            id: ChemicalHazardId::generate(),
+            chemical_id: chemical.id,
+            hazard_id: hazard.id
+        });
+        tables.hazards.push(hazard);
+    }
+    tables.chemicals.push(chemical.clone());
+}
+
+pub fn location(kind: LocationKind, name: &str, parent: Option<&StorageLocation>) -> StorageLocation {
+    StorageLocation {
+        id: LocationId::generate(),
+        parent_id: parent.map(|parent| parent.id),
+        kind,
+        name: name.to_string(),
+        cabinet_type: None
+    }
+}
+
+/// A full, active one liter bottle.
+pub fn container(chemical_id: ChemicalId, location_id: LocationId) -> ChemicalInventory {
+    ChemicalInventory {
+        id: InventoryId::generate(),
+        chemical_id,
+        location_id,
+        lot_number: "".to_string(),
+        purchase_date: None,
+        arrival_date: None,
+        open_date: None,
+        expiration_date: None,
+        disposal_date: None,
+        removal_date: None,
+        disposal_method: "".to_string(),
+        active: true,
+        container_type: ContainerType::Bottle,
+        container_size: 1.0,
+        unit: Unit::Liter,
+        percent_remaining: 100.0
+    }
+}